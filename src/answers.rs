@@ -0,0 +1,135 @@
+use std::fmt::{self, Display};
+use std::fs;
+use std::path::Path;
+
+use crate::input::{file_name, input_root, Source};
+
+/// Outcome of comparing an answer against the known-correct one.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong { expected: String },
+    Unknown,
+}
+
+impl Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "✔ correct"),
+            Verdict::Wrong { expected } => write!(f, "✘ wrong, expected {}", expected),
+            Verdict::Unknown => write!(f, "? unknown"),
+        }
+    }
+}
+
+/// Known-correct answers of a day, read from `answers/NN.txt` of the input root.
+/// The first line holds the answer for part one, the second line the answer for part two.
+/// Missing or empty lines mark an answer as unknown.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    pub part_one: Option<String>,
+    pub part_two: Option<String>,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Answers {
+        let mut lines = contents
+            .lines()
+            .map(|l| Some(l.trim_end_matches('\r')).filter(|l| !l.is_empty()));
+
+        Answers {
+            part_one: lines.next().flatten().map(String::from),
+            part_two: lines.next().flatten().map(String::from),
+        }
+    }
+
+    /// Loads the answers for a day, all answers are unknown if there is no answers file.
+    pub fn load(day: u8) -> Answers {
+        Answers::load_from(&input_root(), day)
+    }
+
+    /// Like `load`, with the answers folder in `root` instead of the default input root.
+    pub fn load_from(root: &Path, day: u8) -> Answers {
+        fs::read_to_string(root.join("answers").join(file_name(day)))
+            .map(|contents| Answers::parse(&contents))
+            .unwrap_or_default()
+    }
+
+    /// The answers an input is checked against. Only puzzle inputs have known answers,
+    /// a file or stdin passed with `--input` leaves all answers unknown.
+    pub fn for_source(source: &Source) -> Answers {
+        match source {
+            Source::Day { root, folder, day } if folder == "inputs" => match root {
+                Some(root) => Answers::load_from(root, *day),
+                None => Answers::load(*day),
+            },
+            _ => Answers::default(),
+        }
+    }
+
+    pub fn get(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part_one.as_deref(),
+            2 => self.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn verify(&self, part: u8, answer: &str) -> Verdict {
+        match self.get(part) {
+            Some(expected) if expected == answer => Verdict::Correct,
+            Some(expected) => Verdict::Wrong {
+                expected: expected.to_string(),
+            },
+            None => Verdict::Unknown,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse("1044\r\n");
+
+        assert_eq!(answers.verify(1, "1044"), Verdict::Correct);
+        assert_eq!(
+            answers.verify(1, "1043"),
+            Verdict::Wrong {
+                expected: "1044".to_string()
+            }
+        );
+        assert_eq!(answers.verify(2, "1054"), Verdict::Unknown);
+    }
+
+    #[test]
+    fn test_for_source() {
+        let root = std::env::temp_dir().join(format!("aoc-answers-{}", std::process::id()));
+        fs::create_dir_all(root.join("answers")).unwrap();
+        fs::write(root.join("answers").join("03.txt"), "438\n266330\n").unwrap();
+
+        let source = Source::Day {
+            root: Some(root.clone()),
+            folder: "inputs".to_string(),
+            day: 3,
+        };
+        let answers = Answers::for_source(&source);
+        fs::remove_dir_all(&root).unwrap();
+
+        assert_eq!(answers.verify(1, "438"), Verdict::Correct);
+        assert_eq!(
+            Answers::for_source(&Source::File("03.txt".into())),
+            Answers::default()
+        );
+        assert_eq!(Answers::for_source(&Source::Stdin), Answers::default());
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse("\n ABCDEF");
+        assert_eq!(answers.part_one, None);
+        assert_eq!(answers.part_two.as_deref(), Some(" ABCDEF"));
+    }
+}
@@ -0,0 +1,214 @@
+use std::env;
+use std::fmt::{self, Display};
+use std::process;
+use std::time::{Duration, Instant};
+
+/// Enables benchmark mode in `solve!`. Either a number of runs (`100`) or a time budget (`500ms`, `2s`).
+pub const BENCH_ENV: &str = "AOC_BENCH";
+/// Number of warm-up runs which are discarded before measuring. Defaults to [DEFAULT_WARMUP].
+pub const WARMUP_ENV: &str = "AOC_BENCH_WARMUP";
+
+pub const DEFAULT_WARMUP: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Budget {
+    Runs(usize),
+    Time(Duration),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Config {
+    pub budget: Budget,
+    pub warmup: usize,
+}
+
+impl Config {
+    pub fn parse(budget: &str, warmup: Option<&str>) -> Option<Config> {
+        let budget = budget.trim();
+
+        let budget = if let Some(ms) = budget.strip_suffix("ms") {
+            Budget::Time(Duration::from_millis(ms.parse().ok()?))
+        } else if let Some(s) = budget.strip_suffix('s') {
+            Budget::Time(Duration::try_from_secs_f64(s.parse().ok()?).ok()?)
+        } else {
+            Budget::Runs(budget.parse().ok().filter(|&n| n > 0)?)
+        };
+
+        if budget == Budget::Time(Duration::ZERO) {
+            return None;
+        }
+
+        let warmup = match warmup {
+            Some(w) => w.trim().parse().ok()?,
+            None => DEFAULT_WARMUP,
+        };
+
+        Some(Config { budget, warmup })
+    }
+
+    /// Reads the configuration from the environment, `None` if benchmark mode is disabled.
+    /// Exits with an error if the variables are set to invalid values.
+    pub fn from_env() -> Option<Config> {
+        let budget = env::var(BENCH_ENV).ok()?;
+        let warmup = env::var(WARMUP_ENV).ok();
+
+        match Config::parse(&budget, warmup.as_deref()) {
+            Some(config) => Some(config),
+            None => {
+                let warmup = warmup
+                    .map(|w| format!(" and {}={:?}", WARMUP_ENV, w))
+                    .unwrap_or_default();
+
+                eprintln!(
+                    "error: invalid {}={:?}{}, expected a number of runs or a time budget like `500ms`",
+                    BENCH_ENV, budget, warmup
+                );
+                process::exit(1);
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub runs: usize,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Stats> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let runs = sorted.len();
+        let min = *sorted.first()?;
+
+        let median = if runs.is_multiple_of(2) {
+            (sorted[runs / 2 - 1] + sorted[runs / 2]) / 2
+        } else {
+            sorted[runs / 2]
+        };
+
+        let nanos: Vec<f64> = sorted.iter().map(|d| d.as_nanos() as f64).collect();
+        let mean = nanos.iter().sum::<f64>() / runs as f64;
+        let variance = nanos.iter().map(|n| (n - mean).powi(2)).sum::<f64>() / runs as f64;
+
+        Some(Stats {
+            runs,
+            min,
+            median,
+            mean: Duration::from_nanos(mean.round() as u64),
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        })
+    }
+}
+
+impl Display for Stats {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "min: {:.2?}, median: {:.2?}, mean: {:.2?}, stddev: {:.2?}, runs: {}",
+            self.min, self.median, self.mean, self.stddev, self.runs
+        )
+    }
+}
+
+/// Runs `func` repeatedly within the budget of `config` and returns the last result with timing statistics.
+pub fn run<T>(config: &Config, mut func: impl FnMut() -> T) -> (T, Stats) {
+    for _ in 0..config.warmup {
+        func();
+    }
+
+    let mut samples = Vec::new();
+    let mut spent = Duration::ZERO;
+
+    let result = loop {
+        let timer = Instant::now();
+        let result = func();
+        let elapsed = timer.elapsed();
+
+        samples.push(elapsed);
+        spent += elapsed;
+
+        let done = match config.budget {
+            Budget::Runs(n) => samples.len() >= n,
+            Budget::Time(budget) => spent >= budget,
+        };
+
+        if done {
+            break result;
+        }
+    };
+
+    (result, Stats::from_samples(&samples).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_config() {
+        assert_eq!(
+            Config::parse("100", None),
+            Some(Config {
+                budget: Budget::Runs(100),
+                warmup: DEFAULT_WARMUP
+            })
+        );
+        assert_eq!(
+            Config::parse("500ms", Some("0")),
+            Some(Config {
+                budget: Budget::Time(Duration::from_millis(500)),
+                warmup: 0
+            })
+        );
+        assert_eq!(
+            Config::parse("1.5s", None).map(|c| c.budget),
+            Some(Budget::Time(Duration::from_millis(1500)))
+        );
+        assert_eq!(Config::parse("0", None), None);
+        assert_eq!(Config::parse("fast", None), None);
+        assert_eq!(Config::parse("-1s", None), None);
+        assert_eq!(Config::parse("NaNs", None), None);
+        assert_eq!(Config::parse("infs", None), None);
+        assert_eq!(Config::parse("0s", None), None);
+        assert_eq!(Config::parse("0ms", None), None);
+    }
+
+    #[test]
+    fn test_stats() {
+        let samples: Vec<Duration> = [4, 2, 8, 6]
+            .iter()
+            .map(|&n| Duration::from_nanos(n))
+            .collect();
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.min, Duration::from_nanos(2));
+        assert_eq!(stats.median, Duration::from_nanos(5));
+        assert_eq!(stats.mean, Duration::from_nanos(5));
+        assert_eq!(stats.stddev, Duration::from_nanos(2));
+        assert_eq!(Stats::from_samples(&[]), None);
+    }
+
+    #[test]
+    fn test_run() {
+        let mut calls = 0;
+        let config = Config {
+            budget: Budget::Runs(5),
+            warmup: 2,
+        };
+
+        let (result, stats) = run(&config, || {
+            calls += 1;
+            calls
+        });
+
+        assert_eq!(result, 7);
+        assert_eq!(stats.runs, 5);
+    }
+}
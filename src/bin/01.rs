@@ -33,27 +33,12 @@ pub fn part_two(input: &str) -> u32 {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 1), part_one, part_two)
+    aoc::solve!(&aoc::read_input(1), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        assert_eq!(part_one("1122"), 3);
-        assert_eq!(part_one("1111"), 4);
-        assert_eq!(part_one("1234"), 0);
-        assert_eq!(part_one("91212129"), 9);
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(part_two("1212"), 6);
-        assert_eq!(part_two("1221"), 0);
-        assert_eq!(part_two("123425"), 4);
-        assert_eq!(part_two("123123"), 12);
-        assert_eq!(part_two("12131415"), 4);
-    }
+    aoc::example_tests!(1, part_one, part_two);
 }
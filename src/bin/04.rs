@@ -35,22 +35,12 @@ pub fn part_two(input: &str) -> u32 {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 4), part_one, part_two)
+    aoc::solve!(&aoc::read_input(4), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        let input = "aa bb cc dd ee\naa bb cc dd aa\naa bb cc dd aaa";
-        assert_eq!(part_one(&input), 2);
-    }
-
-    #[test]
-    fn test_part_two() {
-        let input = "abcde fghij\nabcde xyz ecdab\na ab abc abd abf abj\niiii oiii ooii oooi oooo\noiii ioii iioi iiio is";
-        assert_eq!(part_two(&input), 3);
-    }
+    aoc::example_tests!(4, part_one, part_two);
 }
@@ -32,24 +32,12 @@ pub fn part_two(input: &str) -> u32 {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 5), part_one, part_two)
+    aoc::solve!(&aoc::read_input(5), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 5);
-        assert_eq!(part_one(&input), 5);
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 5);
-        assert_eq!(part_two(&input), 10);
-    }
+    aoc::example_tests!(5, part_one, part_two);
 }
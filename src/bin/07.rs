@@ -23,7 +23,7 @@ struct Tree<'a> {
     parent_edges: HashMap<&'a str, &'a str>,
 }
 
-fn parse(input: &str) -> Tree {
+fn parse(input: &str) -> Tree<'_> {
     let tree = Tree {
         nodes: HashMap::new(),
         child_edges: HashMap::new(),
@@ -69,14 +69,13 @@ fn get_root_node_name<'a>(tree: &'a Tree) -> &'a str {
                 acc
             });
 
-    *tree
-        .nodes
+    tree.nodes
         .keys()
         .find(|p| !child_names.contains(*p))
         .unwrap()
 }
 
-fn get_leaf_node_names<'a, 'b>(tree: &'a Tree<'b>) -> Vec<&'b str> {
+fn get_leaf_node_names<'a>(tree: &Tree<'a>) -> Vec<&'a str> {
     tree.nodes
         .keys()
         .filter_map(|p| {
@@ -193,24 +192,12 @@ pub fn part_two(input: &str) -> u32 {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 7), part_one, part_two)
+    aoc::solve!(&aoc::read_input(7), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 7);
-        assert_eq!(part_one(&input), "tknk".to_string());
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 7);
-        assert_eq!(part_two(&input), 60);
-    }
+    aoc::example_tests!(7, part_one, part_two);
 }
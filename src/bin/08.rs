@@ -26,7 +26,7 @@ struct Instruction<'a> {
     target: &'a str,
 }
 
-fn parse(input: &str) -> Vec<Instruction> {
+fn parse(input: &str) -> Vec<Instruction<'_>> {
     input
         .lines()
         .filter_map(|line| {
@@ -111,24 +111,12 @@ pub fn part_two(input: &str) -> i32 {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 8), part_one, part_two)
+    aoc::solve!(&aoc::read_input(8), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 8);
-        assert_eq!(part_one(&input), 1);
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 8);
-        assert_eq!(part_two(&input), 10);
-    }
+    aoc::example_tests!(8, part_one, part_two);
 }
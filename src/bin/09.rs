@@ -58,34 +58,12 @@ pub fn part_two(input: &str) -> usize {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 9), part_one, part_two)
+    aoc::solve!(&aoc::read_input(9), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        assert_eq!(part_one("{}"), 1);
-        assert_eq!(part_one("{{{}}}"), 6);
-        assert_eq!(part_one("{{},{}}"), 5);
-        assert_eq!(part_one("{{{},{},{{}}}}"), 16);
-        assert_eq!(part_one("{<{},{},{{}}>}"), 1);
-        assert_eq!(part_one("{<a>,<a>,<a>,<a>}"), 1);
-        assert_eq!(part_one("{{<ab>},{<ab>},{<ab>},{<ab>}}"), 9);
-        assert_eq!(part_one("{{<!!>},{<!!>},{<!!>},{<!!>}}"), 9);
-        assert_eq!(part_one("{{<a!>},{<a!>},{<a!>},{<ab>}}"), 3);
-    }
-
-    #[test]
-    fn test_part_two() {
-        assert_eq!(part_two("<>"), 0);
-        assert_eq!(part_two("<random characters>"), 17);
-        assert_eq!(part_two("<<<<>"), 3);
-        assert_eq!(part_two("<{!>}>"), 2);
-        assert_eq!(part_two("<!!>"), 0);
-        assert_eq!(part_two("<!!!>>"), 0);
-        assert_eq!(part_two("<{o\"i!a,<{i<a>"), 10);
-    }
+    aoc::example_tests!(9, part_one, part_two);
 }
@@ -1,22 +1,46 @@
-use aoc::knot_hash::{get_int_list, hash_round, knot_hash};
+use aoc::knot_hash::{knot_hash, KnotHasher, LIST_SIZE};
 
-pub fn part_one(input: &str) -> usize {
+// product of the first two elements after a single round over a list of `size` elements.
+fn check_round(input: &str, size: usize) -> usize {
     let lens: Vec<usize> = input
         .lines()
         .next()
-        .map(|l| l.split(',').map(|s| s.parse().unwrap()).collect())
+        .map(|l| l.split(',').map(|s| s.trim().parse().unwrap()).collect())
         .unwrap();
 
-    let mut list = get_int_list();
-    hash_round(&lens, &mut list, &mut 0, &mut 0);
+    KnotHasher::new()
+        .size(size)
+        .rounds(1)
+        .suffix(&[])
+        .sparse(&lens)
+        .iter()
+        .take(2)
+        .product()
+}
 
-    list.iter().take(2).product()
+pub fn part_one(input: &str) -> usize {
+    check_round(input, LIST_SIZE)
 }
 
 pub fn part_two(input: &str) -> String {
-    knot_hash(input.lines().next().unwrap())
+    knot_hash(input.lines().next().unwrap_or(""))
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 10), part_one, part_two)
+    aoc::solve!(&aoc::read_input(10), part_one, part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        assert_eq!(check_round("3,4,1,5", 5), 12);
+    }
+
+    #[test]
+    fn test_part_two() {
+        aoc::manifest::check(10, 2, part_two);
+    }
 }
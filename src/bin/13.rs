@@ -27,7 +27,7 @@ fn try_pass_firewall(fw: &Firewall, delay: usize) -> Vec<usize> {
     let mut state = vec![];
 
     for (pos, depth) in fw.layers.iter() {
-        if (pos + delay) % ((depth - 1) * 2) == 0 {
+        if (pos + delay).is_multiple_of((depth - 1) * 2) {
             state.push(*pos);
         }
     }
@@ -58,24 +58,12 @@ pub fn part_two(input: &str) -> usize {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 13), part_one, part_two)
+    aoc::solve!(&aoc::read_input(13), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 13);
-        assert_eq!(part_one(&input), 24);
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 13);
-        assert_eq!(part_two(&input), 10);
-    }
+    aoc::example_tests!(13, part_one, part_two);
 }
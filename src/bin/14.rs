@@ -1,89 +1,61 @@
-use aoc::grid::Point;
-use aoc::knot_hash::knot_hash;
-use std::collections::HashSet;
+use aoc::disk::{Disk, Regions};
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::Path;
 
-type Grid = HashSet<Point>;
+// pixels per square in rendered images.
+const PPM_SCALE: usize = 4;
 
-const GRID_SIZE: usize = 128;
-
-fn to_hex(c: char) -> String {
-    format!(
-        "{:04b}",
-        u32::from_str_radix(c.encode_utf8(&mut [0; 1]), 16).unwrap()
-    )
+fn parse(input: &str) -> Disk {
+    Disk::from_key(input.lines().next().unwrap())
 }
 
-fn parse(input: &str) -> Grid {
-    let mut grid = HashSet::new();
-    let hash_key = input.lines().next().unwrap();
-
-    for i in 0..GRID_SIZE {
-        knot_hash(&format!("{}-{}", hash_key, i))
-            .char_indices()
-            .for_each(|(j, c)| {
-                for (k, c) in to_hex(c).char_indices() {
-                    if c == '1' {
-                        grid.insert(Point(i, j * 4 + k));
-                    }
-                }
-            })
-    }
-
-    grid
+pub fn part_one(input: &str) -> u32 {
+    parse(input).used()
 }
 
-pub fn part_one(input: &str) -> usize {
-    let grid = parse(input);
-    grid.len()
+pub fn part_two(input: &str) -> usize {
+    parse(input).regions().count()
 }
 
-fn remove_with_neighbors(grid: &mut Grid, point: Point) {
-    grid.remove(&point);
+/// Writes the regions to `path`, as a PPM image if it ends in `.ppm` and as text otherwise.
+fn render(regions: &Regions, path: &Path) -> io::Result<()> {
+    let mut out = BufWriter::new(File::create(path)?);
 
-    let neighbors: Vec<Point> = point
-        .neighbors(GRID_SIZE - 1, GRID_SIZE - 1, false)
-        .into_iter()
-        .filter(|point| grid.contains(point))
-        .collect();
-
-    for point in neighbors {
-        remove_with_neighbors(grid, point);
+    if path.extension().is_some_and(|e| e == "ppm") {
+        regions.write_ppm(&mut out, PPM_SCALE)?;
+    } else {
+        writeln!(out, "{}", regions.render_ascii())?;
     }
-}
-
-pub fn part_two(input: &str) -> u32 {
-    let mut grid = parse(input);
-
-    let mut groups = 0;
 
-    while !grid.is_empty() {
-        groups += 1;
-        let point = grid.iter().next().cloned().unwrap();
-        remove_with_neighbors(&mut grid, point);
-    }
-
-    groups
+    out.flush()
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 14), part_one, part_two)
+    let input = aoc::read_input(14);
+    let args: Vec<String> = env::args().collect();
+
+    // `--render <path>` draws the disk with its regions, to debug the region count.
+    if let Some(path) = args
+        .iter()
+        .position(|a| a == "--render")
+        .and_then(|i| args.get(i + 1))
+    {
+        let regions = parse(&input).regions();
+
+        match render(&regions, Path::new(path)) {
+            Ok(()) => println!("Rendered {} regions to \"{}\"\n", regions.count(), path),
+            Err(e) => eprintln!("error: could not render to \"{}\": {}", path, e),
+        }
+    }
+
+    aoc::solve!(&input, part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 14);
-        assert_eq!(part_one(&input), 8108);
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 14);
-        assert_eq!(part_two(&input), 1242);
-    }
+    aoc::example_tests!(14, part_one, part_two);
 }
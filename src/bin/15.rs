@@ -7,7 +7,7 @@ const DIV: u64 = 2147483647;
 fn parse(input: &str) -> (u64, u64) {
     input
         .lines()
-        .filter_map(|l| l.split(' ').last().unwrap().parse().ok())
+        .filter_map(|l| l.split(' ').next_back().unwrap().parse().ok())
         .collect_tuple()
         .unwrap()
 }
@@ -18,7 +18,7 @@ fn generate(x: u64, factor: u64) -> u64 {
 
 fn generate_with_fit(x: u64, factor: u64, fit: u64) -> u64 {
     let y = generate(x, factor);
-    if y % fit == 0 {
+    if y.is_multiple_of(fit) {
         y
     } else {
         generate_with_fit(y, factor, fit)
@@ -60,24 +60,12 @@ pub fn part_two(input: &str) -> u64 {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 15), part_one, part_two)
+    aoc::solve!(&aoc::read_input(15), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 15);
-        assert_eq!(part_one(&input), 588);
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 15);
-        assert_eq!(part_two(&input), 309);
-    }
+    aoc::example_tests!(15, part_one, part_two);
 }
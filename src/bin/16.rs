@@ -80,5 +80,5 @@ pub fn part_two(input: &str) -> String {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 16), part_one, part_two)
+    aoc::solve!(&aoc::read_input(16), part_one, part_two)
 }
@@ -32,17 +32,12 @@ pub fn part_two(input: &str) -> usize {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 17), part_one, part_two)
+    aoc::solve!(&aoc::read_input(17), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 17);
-        assert_eq!(part_one(&input), 638);
-    }
+    aoc::example_tests!(17, part_one);
 }
@@ -1,87 +1,42 @@
-enum Direction {
-    North,
-    South,
-    West,
-    East,
-}
-
-type Point = (usize, usize);
+use aoc::grid::{Coord, Direction, Grid, Point, Walker};
 
-fn parse(input: &str) -> Vec<Vec<char>> {
-    input
-        .lines()
-        .filter_map(|l| {
-            if l.is_empty() {
-                None
-            } else {
-                Some(l.chars().collect())
-            }
-        })
-        .collect()
+fn parse(input: &str) -> Grid<char> {
+    Grid::parse_padded(input, ' ', |c| c)
 }
 
-fn entry_point(grid: &[Vec<char>]) -> Point {
-    grid[0]
-        .iter()
-        .enumerate()
-        .find_map(|(x, &val)| if val == '|' { Some((x, 0)) } else { None })
-        .unwrap()
+// everything outside of the grid is empty space.
+fn at(grid: &Grid<char>, coord: Coord) -> char {
+    Point::try_from(coord)
+        .ok()
+        .and_then(|point| grid.get(point))
+        .copied()
+        .unwrap_or(' ')
 }
 
-// bound checks can be skipped as the grid is padded by one line of empty space.
-fn next_point(grid: &[Vec<char>], (x, y): Point, dir: Direction) -> Option<(Point, Direction)> {
-    let mut x = x;
-    let mut y = y;
-    let mut dir = dir;
+fn walk(grid: &Grid<char>) -> (String, u32) {
+    let entry_point = grid.position(|&c| c == '|').unwrap();
+    let mut walker = Walker::new(entry_point.into(), Direction::Down);
+    let mut letter_order = String::new();
+    let mut step_count = 1;
+
+    loop {
+        // at a corner, the path continues to either the left or the right.
+        if at(grid, walker.position) == '+' {
+            let left = walker.heading.turn_left();
 
-    if grid[y][x] != '+' {
-        match dir {
-            Direction::North => y -= 1,
-            Direction::South => y += 1,
-            Direction::East => x += 1,
-            Direction::West => x -= 1,
-        };
-    } else {
-        match dir {
-            Direction::North | Direction::South => {
-                if grid[y][x - 1] != ' ' {
-                    x -= 1;
-                    dir = Direction::West;
-                } else {
-                    x += 1;
-                    dir = Direction::East;
-                }
-            }
-            _ => {
-                if grid[y - 1][x] != ' ' {
-                    y -= 1;
-                    dir = Direction::North;
-                } else {
-                    y += 1;
-                    dir = Direction::South;
-                }
+            if at(grid, left.step(walker.position)) != ' ' {
+                walker.turn_left();
+            } else {
+                walker.turn_right();
             }
         }
-    }
 
-    if grid[y][x] != ' ' {
-        Some(((x, y), dir))
-    } else {
-        None
-    }
-}
-
-fn walk(grid: &[Vec<char>]) -> (String, u32) {
-    let mut pos = entry_point(grid);
-    let mut dir = Direction::South;
-    let mut letter_order = String::new();
-    let mut step_count = 1;
+        let val = at(grid, walker.advance());
+        if val == ' ' {
+            break;
+        }
 
-    while let Some(((x, y), next_dir)) = next_point(grid, pos, dir) {
-        pos = (x, y);
-        dir = next_dir;
         step_count += 1;
-        let val = grid[y][x];
         if val.is_ascii_uppercase() {
             letter_order.push(val);
         }
@@ -99,24 +54,12 @@ pub fn part_two(input: &str) -> u32 {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 19), part_one, part_two)
+    aoc::solve!(&aoc::read_input(19), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 19);
-        assert_eq!(part_one(&input), "ABCDEF".to_string());
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 19);
-        assert_eq!(part_two(&input), 38);
-    }
+    aoc::example_tests!(19, part_one, part_two);
 }
@@ -0,0 +1,172 @@
+use std::collections::HashMap;
+
+type Vector = [i64; 3];
+
+#[derive(Clone, Copy)]
+struct Particle {
+    p: Vector,
+    v: Vector,
+    a: Vector,
+}
+
+impl Particle {
+    fn tick(&mut self) {
+        for i in 0..3 {
+            self.v[i] += self.a[i];
+            self.p[i] += self.v[i];
+        }
+    }
+}
+
+fn parse_vector(s: &str) -> Option<Vector> {
+    let mut it = s.split(',').map(|x| x.trim().parse().ok());
+    Some([it.next()??, it.next()??, it.next()??])
+}
+
+fn parse(input: &str) -> Vec<Particle> {
+    input
+        .lines()
+        .filter_map(|l| {
+            // "p=<1,2,3>, v=<4,5,6>, a=<7,8,9>" => ["p=", "1,2,3", ", v=", "4,5,6", ...]
+            let mut vectors = l
+                .split(['<', '>'])
+                .skip(1)
+                .step_by(2)
+                .filter_map(parse_vector);
+
+            Some(Particle {
+                p: vectors.next()?,
+                v: vectors.next()?,
+                a: vectors.next()?,
+            })
+        })
+        .collect()
+}
+
+fn manhattan(v: &Vector) -> i64 {
+    v.iter().map(|x| x.abs()).sum()
+}
+
+enum Roots {
+    Any,
+    Ticks(Vec<i64>),
+}
+
+fn isqrt(n: i64) -> Option<i64> {
+    let root = (n as f64).sqrt().round() as i64;
+    (root - 1..=root + 1).find(|r| *r >= 0 && r * r == n)
+}
+
+// position after `t` ticks: p + v*t + a*t*(t+1)/2.
+// the difference of two particles on one axis is zero for the integer roots of
+// `da*t^2 + (2*dv + da)*t + 2*dp = 0`.
+fn axis_roots(dp: i64, dv: i64, da: i64) -> Roots {
+    let (a, b, c) = (da, 2 * dv + da, 2 * dp);
+
+    let candidates = if a == 0 {
+        if b == 0 {
+            return if c == 0 {
+                Roots::Any
+            } else {
+                Roots::Ticks(vec![])
+            };
+        }
+
+        if c % b == 0 {
+            vec![-c / b]
+        } else {
+            vec![]
+        }
+    } else {
+        match isqrt(b * b - 4 * a * c) {
+            Some(s) => [-b + s, -b - s]
+                .iter()
+                .filter(|&&n| n % (2 * a) == 0)
+                .map(|&n| n / (2 * a))
+                .collect(),
+            None => vec![],
+        }
+    };
+
+    Roots::Ticks(candidates.into_iter().filter(|&t| t >= 0).collect())
+}
+
+/// Latest tick at which the two particles occupy the same position, if any.
+fn collision_tick(x: &Particle, y: &Particle) -> Option<i64> {
+    let mut ticks: Option<Vec<i64>> = None;
+
+    for i in 0..3 {
+        if let Roots::Ticks(roots) = axis_roots(x.p[i] - y.p[i], x.v[i] - y.v[i], x.a[i] - y.a[i]) {
+            ticks = Some(match ticks {
+                Some(ticks) => ticks.into_iter().filter(|t| roots.contains(t)).collect(),
+                None => roots,
+            });
+        }
+    }
+
+    match ticks {
+        Some(ticks) => ticks.into_iter().max(),
+        // particles are identical on every axis.
+        None => Some(0),
+    }
+}
+
+fn remove_collisions(particles: &mut Vec<Particle>) {
+    let mut counts: HashMap<Vector, usize> = HashMap::new();
+
+    for particle in particles.iter() {
+        *counts.entry(particle.p).or_default() += 1;
+    }
+
+    particles.retain(|particle| counts[&particle.p] == 1);
+}
+
+pub fn part_one(input: &str) -> usize {
+    // in the long run, acceleration dominates velocity which dominates the starting position.
+    parse(input)
+        .iter()
+        .enumerate()
+        .min_by_key(|(_, particle)| {
+            (
+                manhattan(&particle.a),
+                manhattan(&particle.v),
+                manhattan(&particle.p),
+            )
+        })
+        .map(|(i, _)| i)
+        .unwrap()
+}
+
+pub fn part_two(input: &str) -> usize {
+    let mut particles = parse(input);
+
+    // no pair of particles can collide after this tick, so the simulation can stop there.
+    let last_collision = particles
+        .iter()
+        .enumerate()
+        .flat_map(|(i, x)| particles[i + 1..].iter().map(move |y| (x, y)))
+        .filter_map(|(x, y)| collision_tick(x, y))
+        .max();
+
+    if let Some(last_collision) = last_collision {
+        remove_collisions(&mut particles);
+
+        for _ in 0..last_collision {
+            particles.iter_mut().for_each(|particle| particle.tick());
+            remove_collisions(&mut particles);
+        }
+    }
+
+    particles.len()
+}
+
+fn main() {
+    aoc::solve!(&aoc::read_input(20), part_one, part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    aoc::example_tests!(20, part_one, part_two);
+}
@@ -0,0 +1,151 @@
+const START: &str = ".#./..#/###";
+
+/// A square of pixels, stored row by row.
+#[derive(Clone)]
+struct Image {
+    size: usize,
+    pixels: Vec<bool>,
+}
+
+impl Image {
+    fn parse(s: &str) -> Image {
+        let pixels: Vec<bool> = s.chars().filter(|&c| c != '/').map(|c| c == '#').collect();
+        let size = (pixels.len() as f64).sqrt() as usize;
+        Image { size, pixels }
+    }
+
+    fn rotate(&self) -> Image {
+        let size = self.size;
+        let mut pixels = vec![false; size * size];
+
+        for y in 0..size {
+            for x in 0..size {
+                pixels[x * size + (size - 1 - y)] = self.pixels[y * size + x];
+            }
+        }
+
+        Image { size, pixels }
+    }
+
+    fn flip(&self) -> Image {
+        let pixels = self
+            .pixels
+            .chunks(self.size)
+            .flat_map(|row| row.iter().rev().copied())
+            .collect();
+
+        Image {
+            size: self.size,
+            pixels,
+        }
+    }
+
+    /// Encodes the `block`x`block` square starting at `x, y` as a bitmask.
+    fn key(&self, x: usize, y: usize, block: usize) -> usize {
+        (0..block)
+            .flat_map(|dy| (0..block).map(move |dx| (dx, dy)))
+            .fold(0, |acc, (dx, dy)| {
+                (acc << 1) | self.pixels[(y + dy) * self.size + x + dx] as usize
+            })
+    }
+
+    fn count(&self) -> usize {
+        self.pixels.iter().filter(|&&p| p).count()
+    }
+}
+
+/// Rules for 2x2 and 3x3 inputs, indexed by the bitmask of the input pattern.
+struct Rules {
+    two: Vec<Option<Image>>,
+    three: Vec<Option<Image>>,
+}
+
+impl Rules {
+    fn get(&self, block: usize, key: usize) -> &Image {
+        let rules = if block == 2 { &self.two } else { &self.three };
+        rules[key].as_ref().expect("no rule matches pattern")
+    }
+}
+
+fn parse(input: &str) -> Rules {
+    let mut rules = Rules {
+        two: vec![None; 1 << 4],
+        three: vec![None; 1 << 9],
+    };
+
+    for line in input.lines().filter(|l| !l.is_empty()) {
+        let (from, to) = line.split_once(" => ").unwrap();
+        let from = Image::parse(from);
+        let to = Image::parse(to);
+
+        // expand every rule into all rotations and flips up front.
+        let mut variant = from;
+        for _ in 0..4 {
+            for image in [variant.clone(), variant.flip()] {
+                let key = image.key(0, 0, image.size);
+                let target = if image.size == 2 {
+                    &mut rules.two
+                } else {
+                    &mut rules.three
+                };
+                target[key] = Some(to.clone());
+            }
+            variant = variant.rotate();
+        }
+    }
+
+    rules
+}
+
+fn enhance(image: &Image, rules: &Rules) -> Image {
+    let block = if image.size.is_multiple_of(2) { 2 } else { 3 };
+    let blocks = image.size / block;
+    let out_block = block + 1;
+    let size = blocks * out_block;
+    let mut pixels = vec![false; size * size];
+
+    for by in 0..blocks {
+        for bx in 0..blocks {
+            let rule = rules.get(block, image.key(bx * block, by * block, block));
+
+            for (i, row) in rule.pixels.chunks(out_block).enumerate() {
+                let start = (by * out_block + i) * size + bx * out_block;
+                pixels[start..start + out_block].copy_from_slice(row);
+            }
+        }
+    }
+
+    Image { size, pixels }
+}
+
+fn pixels_after(input: &str, iterations: usize) -> usize {
+    let rules = parse(input);
+
+    (0..iterations)
+        .fold(Image::parse(START), |image, _| enhance(&image, &rules))
+        .count()
+}
+
+pub fn part_one(input: &str) -> usize {
+    pixels_after(input, 5)
+}
+
+pub fn part_two(input: &str) -> usize {
+    pixels_after(input, 18)
+}
+
+fn main() {
+    aoc::solve!(&aoc::read_input(21), part_one, part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        use aoc::read_file;
+        let input = read_file("examples", 21).unwrap();
+        assert_eq!(pixels_after(&input, 2), 12);
+    }
+}
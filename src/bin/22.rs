@@ -1,9 +1,4 @@
-use hashbrown::HashMap;
-
-type Infections = HashMap<Point, State>;
-
-#[derive(Copy, Clone, Eq, Hash, PartialEq)]
-struct Point(i32, i32);
+use aoc::grid::{Coord, Direction, SparseGrid, Walker};
 
 #[derive(PartialEq)]
 enum State {
@@ -13,114 +8,63 @@ enum State {
     Weakened,
 }
 
-enum Direction {
-    Down,
-    Left,
-    Right,
-    Up,
-}
-
-fn parse(input: &str) -> (Infections, Point) {
-    let mut infections: Infections = HashMap::new();
+fn parse(input: &str) -> (SparseGrid<State>, Coord) {
+    let mut infections = SparseGrid::new();
     let mut size_x = 0;
     let mut size_y = 0;
 
     input.lines().enumerate().for_each(|(y, l)| {
         size_y = y as i32;
         l.chars().enumerate().for_each(|(x, c)| {
-            let p = Point(x as i32, y as i32);
-
             if y == 0 {
                 size_x = x as i32;
             }
 
             if c == '#' {
-                infections.insert(p, State::Infected);
+                infections.insert(Coord(x as i32, y as i32), State::Infected);
             }
         });
     });
 
-    (infections, Point(size_x / 2, size_y / 2))
-}
-
-fn go(dir: &Direction, pos: &Point) -> Point {
-    match dir {
-        Direction::Down => Point(pos.0, pos.1 + 1),
-        Direction::Left => Point(pos.0 - 1, pos.1),
-        Direction::Right => Point(pos.0 + 1, pos.1),
-        Direction::Up => Point(pos.0, pos.1 - 1),
-    }
-}
-
-fn turn(dir: &Direction, left: bool) -> Direction {
-    match dir {
-        Direction::Down => {
-            if left {
-                Direction::Right
-            } else {
-                Direction::Left
-            }
-        }
-        Direction::Left => {
-            if left {
-                Direction::Down
-            } else {
-                Direction::Up
-            }
-        }
-        Direction::Right => {
-            if left {
-                Direction::Up
-            } else {
-                Direction::Down
-            }
-        }
-        Direction::Up => {
-            if left {
-                Direction::Left
-            } else {
-                Direction::Right
-            }
-        }
-    }
+    (infections, Coord(size_x / 2, size_y / 2))
 }
 
 pub fn part_one(input: &str) -> u32 {
-    let (mut infections, mut pos) = parse(input);
-    let mut dir = Direction::Up;
+    let (mut infections, start) = parse(input);
+    let mut carrier = Walker::new(start, Direction::Up);
     let mut cycles_with_infections = 0;
 
     for _ in 0..10000 {
-        let state = infections.entry(pos).or_insert(State::Clean);
-        let is_clean = *state == State::Clean;
-        dir = turn(&dir, is_clean);
+        let state = infections.get_or_insert_with(carrier.position, || State::Clean);
 
-        if is_clean {
+        if *state == State::Clean {
+            carrier.turn_left();
             *state = State::Infected;
             cycles_with_infections += 1;
         } else {
+            carrier.turn_right();
             *state = State::Clean;
         }
 
-        pos = go(&dir, &pos)
+        carrier.advance();
     }
 
     cycles_with_infections
 }
 
 pub fn part_two(input: &str) -> u32 {
-    let (mut infections, mut pos) = parse(input);
-    let mut dir = Direction::Up;
+    let (mut infections, start) = parse(input);
+    let mut carrier = Walker::new(start, Direction::Up);
     let mut cycles_with_infections = 0;
 
     for _ in 0..10000000 {
-        let state = infections.entry(pos).or_insert(State::Clean);
+        let state = infections.get_or_insert_with(carrier.position, || State::Clean);
 
-        dir = match *state {
-            State::Clean => turn(&dir, true),
-            State::Flagged => turn(&turn(&dir, true), true),
-            State::Infected => turn(&dir, false),
-            State::Weakened => dir,
+        match *state {
+            State::Clean => carrier.turn_left(),
+            State::Flagged => carrier.reverse(),
+            State::Infected => carrier.turn_right(),
+            State::Weakened => {}
         };
 
         *state = match *state {
@@ -134,31 +78,19 @@ pub fn part_two(input: &str) -> u32 {
             cycles_with_infections += 1;
         }
 
-        pos = go(&dir, &pos);
+        carrier.advance();
     }
 
     cycles_with_infections
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 22), part_one, part_two)
+    aoc::solve!(&aoc::read_input(22), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 22);
-        assert_eq!(part_one(&input), 5587);
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 22);
-        assert_eq!(part_two(&input), 2511944);
-    }
+    aoc::example_tests!(22, part_one, part_two);
 }
@@ -0,0 +1,88 @@
+use aoc::duet::{parse, Instance, Instruction, Value};
+
+fn is_composite(n: i64) -> bool {
+    n > 3 && (2..).take_while(|d| d * d <= n).any(|d| n % d == 0)
+}
+
+pub fn part_one(input: &str) -> usize {
+    let instructions = parse(input);
+
+    let mut instance = Instance::new(0);
+    let mut mul_count = 0;
+
+    while let Some(instruction) = instance.current(&instructions) {
+        if let Instruction::Mul(_, _) = instruction {
+            mul_count += 1;
+        }
+
+        instance.exec(instruction);
+    }
+
+    mul_count
+}
+
+// the program counts the composite numbers in `b..=c`, stepping by a constant.
+// interpreting that takes billions of instructions, so the setup section is run
+// to find the bounds and the count is computed directly.
+pub fn part_two(input: &str) -> usize {
+    let instructions = parse(input);
+
+    let mut instance = Instance::new(0);
+    instance.set('a', 1);
+
+    // the setup ends where the loop resets the `f` flag.
+    while let Some(instruction) = instance.current(&instructions) {
+        if let Instruction::Set('f', Value::Int(1)) = instruction {
+            break;
+        }
+
+        instance.exec(instruction);
+    }
+
+    let step = instructions
+        .iter()
+        .rev()
+        .find_map(|i| match i {
+            Instruction::Sub('b', Value::Int(x)) => Some(-x),
+            _ => None,
+        })
+        .expect("could not find loop increment");
+
+    (instance.get('b')..=instance.get('c'))
+        .step_by(step as usize)
+        .filter(|&n| is_composite(n))
+        .count()
+}
+
+fn main() {
+    aoc::solve!(&aoc::read_input(23), part_one, part_two)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        use aoc::read_file;
+        let input = read_file("examples", 23).unwrap();
+        assert_eq!(part_one(&input), 25);
+    }
+
+    #[test]
+    fn test_part_two() {
+        use aoc::read_file;
+        let input = read_file("examples", 23).unwrap();
+
+        // the example is small enough to be interpreted in full.
+        let instructions = parse(&input);
+        let mut instance = Instance::new(0);
+        instance.set('a', 1);
+        while let Some(instruction) = instance.current(&instructions) {
+            instance.exec(instruction);
+        }
+
+        assert_eq!(instance.get('h'), 2);
+        assert_eq!(part_two(&input), 2);
+    }
+}
@@ -77,24 +77,12 @@ pub fn part_two(input: &str) -> u32 {
 }
 
 fn main() {
-    aoc::solve!(&aoc::read_file("inputs", 24), part_one, part_two)
+    aoc::solve!(&aoc::read_input(24), part_one, part_two)
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn test_part_one() {
-        use aoc::read_file;
-        let input = read_file("examples", 24);
-        assert_eq!(part_one(&input), 31);
-    }
-
-    #[test]
-    fn test_part_two() {
-        use aoc::read_file;
-        let input = read_file("examples", 24);
-        assert_eq!(part_two(&input), 19);
-    }
+    aoc::example_tests!(24, part_one, part_two);
 }
@@ -0,0 +1,161 @@
+//! Prints knot hashes of files, or of every line read from stdin.
+//! Like the lines read from stdin, a file is hashed without one trailing newline.
+//!
+//! `knot-hash [--verify <digest>] [<file>...]`
+use aoc::knot_hash::KnotDigest;
+use std::env;
+use std::fs;
+use std::io::{self, BufRead};
+use std::process;
+
+const USAGE: &str = "usage: knot-hash [--verify <digest>] [<file>...]";
+
+struct Args {
+    help: bool,
+    verify: Option<KnotDigest>,
+    files: Vec<String>,
+}
+
+fn parse_args(args: &[String]) -> Result<Args, String> {
+    let mut help = false;
+    let mut verify = None;
+    let mut files = Vec::new();
+    let mut args = args.iter();
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--verify" => {
+                let digest = args.next().ok_or("--verify requires a digest")?;
+                verify = Some(
+                    digest
+                        .parse()
+                        .map_err(|e| format!("invalid digest: {}", e))?,
+                );
+            }
+            "-h" | "--help" => help = true,
+            _ => files.push(arg.clone()),
+        }
+    }
+
+    Ok(Args {
+        help,
+        verify,
+        files,
+    })
+}
+
+/// The contents of a file without one trailing `\n` or `\r\n`.
+fn strip_newline(contents: &[u8]) -> &[u8] {
+    match contents.strip_suffix(b"\n") {
+        Some(line) => line.strip_suffix(b"\r").unwrap_or(line),
+        None => contents,
+    }
+}
+
+/// Prints one line per hashed input, returns whether all of them matched the expected digest.
+fn report(name: &str, digest: KnotDigest, verify: Option<KnotDigest>) -> bool {
+    match verify {
+        Some(expected) if expected == digest => {
+            println!("{}: OK", name);
+            true
+        }
+        Some(_) => {
+            println!("{}: FAILED ({})", name, digest);
+            false
+        }
+        None if name.is_empty() => {
+            println!("{}", digest);
+            true
+        }
+        None => {
+            println!("{}  {}", digest, name);
+            true
+        }
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let args = parse_args(&args).unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        process::exit(2);
+    });
+
+    if args.help {
+        println!("{}", USAGE);
+        return;
+    }
+
+    let mut all_match = true;
+
+    if args.files.is_empty() {
+        for line in io::stdin().lock().lines() {
+            let line = line.unwrap_or_else(|e| {
+                eprintln!("error: could not read stdin: {}", e);
+                process::exit(2);
+            });
+
+            // lines are named by their content when verifying, so failures can be told apart.
+            let name = if args.verify.is_some() { &line } else { "" };
+            all_match &= report(name, KnotDigest::of(&line), args.verify);
+        }
+    } else {
+        for file in &args.files {
+            let contents = fs::read(file).unwrap_or_else(|e| {
+                eprintln!("error: could not read \"{}\": {}", file, e);
+                process::exit(2);
+            });
+
+            all_match &= report(file, KnotDigest::of(strip_newline(&contents)), args.verify);
+        }
+    }
+
+    if !all_match {
+        process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(s: &str) -> Vec<String> {
+        s.split_whitespace().map(String::from).collect()
+    }
+
+    #[test]
+    fn test_parse_args() {
+        let parsed = parse_args(&args(
+            "a.txt --verify 33efeb34ea91902bb2f59c9920caa6cd b.txt",
+        ))
+        .unwrap();
+
+        assert_eq!(parsed.files, vec!["a.txt", "b.txt"]);
+        assert_eq!(parsed.verify, Some(KnotDigest::of("AoC 2017")));
+
+        assert!(!parsed.help);
+        assert!(parse_args(&args("a.txt --help")).unwrap().help);
+
+        assert!(parse_args(&args("--verify")).is_err());
+        assert!(parse_args(&args("--verify abc")).is_err());
+    }
+
+    #[test]
+    fn test_strip_newline() {
+        assert_eq!(strip_newline(b"AoC 2017\n"), b"AoC 2017");
+        assert_eq!(strip_newline(b"AoC 2017\r\n"), b"AoC 2017");
+        assert_eq!(strip_newline(b"AoC 2017\n\n"), b"AoC 2017\n");
+        assert_eq!(strip_newline(b"AoC 2017"), b"AoC 2017");
+        assert_eq!(strip_newline(b"AoC 2017\r"), b"AoC 2017\r");
+    }
+
+    #[test]
+    fn test_report() {
+        let digest = KnotDigest::of("AoC 2017");
+
+        assert!(report("a.txt", digest, None));
+        assert!(report("a.txt", digest, Some(digest)));
+        assert!(!report("a.txt", digest, Some(KnotDigest::of(""))));
+    }
+}
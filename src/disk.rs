@@ -0,0 +1,216 @@
+use crate::grid::{Grid, Point};
+use crate::knot_hash::knot_hash_batch;
+use std::io::{self, Write};
+
+/// Every row of the disk is the 128 bits of one knot hash.
+pub const DISK_WIDTH: usize = 128;
+pub const DISK_HEIGHT: usize = 128;
+
+const REGION_CHARS: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const FREE_COLOR: [u8; 3] = [24, 24, 24];
+
+/// The used squares of a disk, one bit per square. The most significant bit is the leftmost square.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Disk {
+    rows: Vec<u128>,
+}
+
+impl Disk {
+    /// The disk of a puzzle key, row `y` is the knot hash of `<key>-<y>`.
+    pub fn from_key(key: &str) -> Self {
+        let keys: Vec<String> = (0..DISK_HEIGHT).map(|y| format!("{}-{}", key, y)).collect();
+
+        Disk::from_rows(
+            knot_hash_batch(&keys)
+                .into_iter()
+                .map(u128::from_be_bytes)
+                .collect(),
+        )
+    }
+
+    pub fn from_rows(rows: Vec<u128>) -> Self {
+        Disk { rows }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    pub fn is_used(&self, Point(x, y): Point) -> bool {
+        x < DISK_WIDTH
+            && self
+                .rows
+                .get(y)
+                .is_some_and(|row| row >> (127 - x) & 1 == 1)
+    }
+
+    /// Number of used squares.
+    pub fn used(&self) -> u32 {
+        self.rows.iter().map(|row| row.count_ones()).sum()
+    }
+
+    /// Labels the regions of adjacent used squares, without modifying the disk.
+    pub fn regions(&self) -> Regions {
+        let mut labels = Grid::new(DISK_WIDTH, self.height(), None);
+        let mut sizes = Vec::new();
+
+        for start in labels.points().collect::<Vec<_>>() {
+            if !self.is_used(start) || labels[start].is_some() {
+                continue;
+            }
+
+            let id = sizes.len();
+            let mut size = 0;
+            let mut stack = vec![start];
+            labels[start] = Some(id);
+
+            while let Some(point) = stack.pop() {
+                size += 1;
+
+                for neighbor in labels.neighbors(point, false) {
+                    if self.is_used(neighbor) && labels[neighbor].is_none() {
+                        labels[neighbor] = Some(id);
+                        stack.push(neighbor);
+                    }
+                }
+            }
+
+            sizes.push(size);
+        }
+
+        Regions { labels, sizes }
+    }
+}
+
+/// The region of every used square. Regions are numbered in the order of their first square, row by row.
+#[derive(Clone, Debug)]
+pub struct Regions {
+    labels: Grid<Option<usize>>,
+    sizes: Vec<usize>,
+}
+
+impl Regions {
+    pub fn count(&self) -> usize {
+        self.sizes.len()
+    }
+
+    /// The region of a square, `None` if it is free.
+    pub fn label(&self, point: Point) -> Option<usize> {
+        self.labels.get(point).copied().flatten()
+    }
+
+    /// Number of squares per region, indexed by region.
+    pub fn sizes(&self) -> &[usize] {
+        &self.sizes
+    }
+
+    /// One character per square: `.` for free squares, otherwise a character that cycles with the region.
+    pub fn render_ascii(&self) -> String {
+        self.labels
+            .map(|label| match label {
+                Some(id) => REGION_CHARS[id % REGION_CHARS.len()] as char,
+                None => '.',
+            })
+            .to_string()
+    }
+
+    /// Writes a binary PPM image with `scale` pixels per square and one colour per region.
+    pub fn write_ppm(&self, out: &mut impl Write, scale: usize) -> io::Result<()> {
+        let scale = scale.max(1);
+        let width = self.labels.width() * scale;
+        let height = self.labels.height() * scale;
+
+        write!(out, "P6\n{} {}\n255\n", width, height)?;
+
+        for row in self.labels.rows() {
+            let line: Vec<u8> = row
+                .iter()
+                .flat_map(|label| {
+                    let color = label.map_or(FREE_COLOR, region_color);
+                    std::iter::repeat_n(color, scale).flatten()
+                })
+                .collect();
+
+            for _ in 0..scale {
+                out.write_all(&line)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+// spreads the hues of consecutive regions by the golden angle, so neighbors are easy to tell apart.
+fn region_color(id: usize) -> [u8; 3] {
+    let hue = (id as f64 * 137.508) % 360.0;
+    let x = 1.0 - ((hue / 60.0) % 2.0 - 1.0).abs();
+
+    let (r, g, b) = match (hue / 60.0) as u8 {
+        0 => (1.0, x, 0.0),
+        1 => (x, 1.0, 0.0),
+        2 => (0.0, 1.0, x),
+        3 => (0.0, x, 1.0),
+        4 => (x, 0.0, 1.0),
+        _ => (1.0, 0.0, x),
+    };
+
+    // keep colours away from the dark background.
+    let channel = |c: f64| (64.0 + c * 191.0) as u8;
+    [channel(r), channel(g), channel(b)]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // ##.#
+    // .#.#
+    // ##..
+    fn disk() -> Disk {
+        Disk::from_rows(vec![0b1101 << 124, 0b0101 << 124, 0b1100 << 124])
+    }
+
+    #[test]
+    fn test_regions() {
+        let disk = disk();
+        let regions = disk.regions();
+
+        assert_eq!(disk.used(), 7);
+        assert_eq!(regions.count(), 2);
+        assert_eq!(regions.sizes(), &[5, 2]);
+        assert_eq!(regions.label(Point(1, 2)), Some(0));
+        assert_eq!(regions.label(Point(3, 1)), Some(1));
+        assert_eq!(regions.label(Point(2, 0)), None);
+
+        // labelling leaves the disk as it was.
+        assert_eq!(disk, self::disk());
+    }
+
+    #[test]
+    fn test_render() {
+        let ascii = disk().regions().render_ascii();
+        let first_row = ascii.lines().next().unwrap();
+
+        assert_eq!(first_row.len(), DISK_WIDTH);
+        assert!(first_row.starts_with("00.1."));
+
+        let mut ppm = Vec::new();
+        disk().regions().write_ppm(&mut ppm, 2).unwrap();
+
+        let header = b"P6\n256 6\n255\n";
+        assert!(ppm.starts_with(header));
+        assert_eq!(ppm.len(), header.len() + 256 * 6 * 3);
+        assert_eq!(ppm[header.len() + 4 * 3..header.len() + 5 * 3], FREE_COLOR);
+    }
+
+    #[test]
+    fn test_from_key() {
+        let disk = Disk::from_key("flqrgnkx");
+
+        assert_eq!(disk.height(), DISK_HEIGHT);
+        assert_eq!(disk.used(), 8108);
+        assert_eq!(disk.regions().count(), 1242);
+        // ##.#.#..
+        assert!(disk.is_used(Point(0, 0)) && !disk.is_used(Point(2, 0)));
+    }
+}
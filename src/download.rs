@@ -0,0 +1,217 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+/// Session cookie of an adventofcode.com login. Takes precedence over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+/// Name of the session file in the home directory, shared with `aoc-cli`.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+/// Overrides the server that inputs are downloaded from.
+pub const URL_ENV: &str = "AOC_URL";
+
+pub const DEFAULT_URL: &str = "https://adventofcode.com";
+pub const DEFAULT_YEAR: u16 = 2017;
+
+const USER_AGENT: &str = "github.com/fspoettel/advent-of-code-2017";
+
+#[derive(Debug)]
+pub enum DownloadError {
+    NoSession,
+    Session { path: PathBuf, source: io::Error },
+    Request(Box<ureq::Error>),
+    Status(u16),
+    Write { path: PathBuf, source: io::Error },
+}
+
+impl Display for DownloadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DownloadError::NoSession => write!(
+                f,
+                "no session found. Set `{}` or save the session cookie to \"~/{}\"",
+                SESSION_ENV, SESSION_FILE
+            ),
+            DownloadError::Session { path, source } => {
+                write!(
+                    f,
+                    "could not read session file \"{}\": {}",
+                    path.display(),
+                    source
+                )
+            }
+            DownloadError::Request(e) => write!(f, "request failed: {}", e),
+            DownloadError::Status(status) => write!(f, "server responded with status {}", status),
+            DownloadError::Write { path, source } => {
+                write!(f, "could not write \"{}\": {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl Error for DownloadError {}
+
+/// Reads the session from an explicit file, `$AOC_SESSION` or `~/.adventofcode.session`, in that order.
+pub fn read_session(file: Option<&Path>) -> Result<String, DownloadError> {
+    let path = match file {
+        Some(file) => file.to_path_buf(),
+        None => {
+            if let Ok(session) = env::var(SESSION_ENV) {
+                return Ok(session.trim().to_string());
+            }
+
+            env::var_os("HOME")
+                .map(|home| Path::new(&home).join(SESSION_FILE))
+                .filter(|path| path.exists())
+                .ok_or(DownloadError::NoSession)?
+        }
+    };
+
+    fs::read_to_string(&path)
+        .map(|session| session.trim().to_string())
+        .map_err(|source| DownloadError::Session { path, source })
+}
+
+/// `$AOC_URL` if set, adventofcode.com otherwise.
+pub fn base_url() -> String {
+    env::var(URL_ENV).unwrap_or_else(|_| DEFAULT_URL.to_string())
+}
+
+pub fn fetch(base_url: &str, year: u16, day: u8, session: &str) -> Result<String, DownloadError> {
+    let url = format!(
+        "{}/{}/day/{}/input",
+        base_url.trim_end_matches('/'),
+        year,
+        day
+    );
+
+    let response = ureq::get(&url)
+        .set("Cookie", &format!("session={}", session))
+        .set("User-Agent", USER_AGENT)
+        .call()
+        .map_err(|e| match e {
+            ureq::Error::Status(status, _) => DownloadError::Status(status),
+            e => DownloadError::Request(Box::new(e)),
+        })?;
+
+    response
+        .into_string()
+        .map_err(|e| DownloadError::Request(Box::new(e.into())))
+}
+
+/// Writes to a temporary file next to `path` first, so `path` never holds a partial download.
+pub fn write_atomic(path: &Path, contents: &str) -> Result<(), DownloadError> {
+    let tmp_path = path.with_extension("txt.tmp");
+
+    let write = || -> io::Result<()> {
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let mut file = fs::File::create(&tmp_path)?;
+        file.write_all(contents.as_bytes())?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)
+    };
+
+    write().map_err(|source| {
+        let _ = fs::remove_file(&tmp_path);
+        DownloadError::Write {
+            path: path.to_path_buf(),
+            source,
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single request with `status` and `body`, returns the server url and the received request head.
+    fn serve(status: &'static str, body: &'static str) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+            }
+
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+
+            request
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn test_fetch() {
+        let (url, server) = serve("200 OK", "0/2\n2/2\n");
+        let input = fetch(&url, 2017, 24, "abc").unwrap();
+        let request = server.join().unwrap();
+
+        assert_eq!(input, "0/2\n2/2\n");
+        assert!(request.starts_with("GET /2017/day/24/input HTTP/1.1"));
+        assert!(request.contains("session=abc"));
+    }
+
+    #[test]
+    fn test_fetch_status() {
+        let (url, server) = serve("400 Bad Request", "Please log in.");
+        let result = fetch(&url, 2017, 1, "expired");
+        server.join().unwrap();
+
+        assert!(matches!(result, Err(DownloadError::Status(400))));
+    }
+
+    #[test]
+    fn test_read_session_file() {
+        let path = env::temp_dir().join(format!("aoc-session-{}.txt", std::process::id()));
+        fs::write(&path, "abc\n").unwrap();
+
+        // an explicit file is used even when the environment holds a session.
+        env::set_var(SESSION_ENV, "from-env");
+        let session = read_session(Some(&path));
+        env::remove_var(SESSION_ENV);
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(session.unwrap(), "abc");
+        assert!(matches!(
+            read_session(Some(&path)),
+            Err(DownloadError::Session { .. })
+        ));
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let path = env::temp_dir().join(format!("aoc-download-{}.txt", std::process::id()));
+
+        write_atomic(&path, "1122").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1122");
+        assert!(!path.with_extension("txt.tmp").exists());
+
+        fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,261 @@
+use std::collections::{HashMap, VecDeque};
+
+/// An operand is either a literal integer or the value of a register.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Value {
+    Reg(char),
+    Int(i64),
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Snd(Value),
+    Set(char, Value),
+    Add(char, Value),
+    Sub(char, Value),
+    Mul(char, Value),
+    Mod(char, Value),
+    Rcv(char),
+    Jgz(Value, Value),
+    Jnz(Value, Value),
+}
+
+fn parse_register(s: &str) -> Option<char> {
+    let mut chars = s.chars();
+    let c = chars.next()?;
+
+    if chars.next().is_none() && c.is_ascii_lowercase() {
+        Some(c)
+    } else {
+        None
+    }
+}
+
+fn parse_value(s: &str) -> Option<Value> {
+    match s.parse() {
+        Ok(i) => Some(Value::Int(i)),
+        Err(_) => parse_register(s).map(Value::Reg),
+    }
+}
+
+pub fn parse(input: &str) -> Vec<Instruction> {
+    input
+        .lines()
+        .filter_map(|l| {
+            let mut parts = l.split(' ');
+            let instruction = parts.next()?;
+            let x = parts.next()?;
+
+            match instruction {
+                "snd" => return Some(Instruction::Snd(parse_value(x)?)),
+                "rcv" => return Some(Instruction::Rcv(parse_register(x)?)),
+                _ => {}
+            }
+
+            let y = parse_value(parts.next()?)?;
+
+            match instruction {
+                "set" => Some(Instruction::Set(parse_register(x)?, y)),
+                "add" => Some(Instruction::Add(parse_register(x)?, y)),
+                "sub" => Some(Instruction::Sub(parse_register(x)?, y)),
+                "mul" => Some(Instruction::Mul(parse_register(x)?, y)),
+                "mod" => Some(Instruction::Mod(parse_register(x)?, y)),
+                "jgz" => Some(Instruction::Jgz(parse_value(x)?, y)),
+                "jnz" => Some(Instruction::Jnz(parse_value(x)?, y)),
+                _ => None,
+            }
+        })
+        .collect()
+}
+
+/// Outcome of executing a single instruction with duet semantics.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Status {
+    Running,
+    Sent(i64),
+    Blocked,
+    Terminated,
+}
+
+/// A single program instance with its own registers, instruction pointer and receive queue.
+pub struct Instance {
+    registers: HashMap<char, i64>,
+    pub pos: i64,
+    pub queue: VecDeque<i64>,
+    pub sent: usize,
+}
+
+impl Instance {
+    pub fn new(id: i64) -> Self {
+        let mut registers = HashMap::new();
+        registers.insert('p', id);
+
+        Instance {
+            registers,
+            pos: 0,
+            queue: VecDeque::new(),
+            sent: 0,
+        }
+    }
+
+    pub fn get(&self, reg: char) -> i64 {
+        *self.registers.get(&reg).unwrap_or(&0)
+    }
+
+    pub fn get_val(&self, val: &Value) -> i64 {
+        match val {
+            Value::Int(i) => *i,
+            Value::Reg(c) => self.get(*c),
+        }
+    }
+
+    pub fn set(&mut self, reg: char, val: i64) {
+        self.registers.insert(reg, val);
+    }
+
+    /// The instruction at the current position, `None` once the instance jumped out of the program.
+    pub fn current<'a>(&self, program: &'a [Instruction]) -> Option<&'a Instruction> {
+        usize::try_from(self.pos)
+            .ok()
+            .and_then(|pos| program.get(pos))
+    }
+
+    /// Executes an instruction which only touches local state and advances the position.
+    /// `snd` and `rcv` are skipped, their meaning depends on the caller.
+    pub fn exec(&mut self, instruction: &Instruction) {
+        let mut increment = 1;
+
+        match instruction {
+            Instruction::Set(x, y) => self.set(*x, self.get_val(y)),
+            Instruction::Add(x, y) => self.set(*x, self.get(*x) + self.get_val(y)),
+            Instruction::Sub(x, y) => self.set(*x, self.get(*x) - self.get_val(y)),
+            Instruction::Mul(x, y) => self.set(*x, self.get(*x) * self.get_val(y)),
+            Instruction::Mod(x, y) => self.set(*x, self.get(*x) % self.get_val(y)),
+            Instruction::Jgz(x, y) => {
+                if self.get_val(x) > 0 {
+                    increment = self.get_val(y);
+                }
+            }
+            Instruction::Jnz(x, y) => {
+                if self.get_val(x) != 0 {
+                    increment = self.get_val(y);
+                }
+            }
+            Instruction::Snd(_) | Instruction::Rcv(_) => {}
+        };
+
+        self.pos += increment;
+    }
+
+    /// Executes the current instruction with duet semantics: `snd` emits a message,
+    /// `rcv` pops from the queue and blocks while it is empty.
+    pub fn step(&mut self, program: &[Instruction]) -> Status {
+        let instruction = match self.current(program) {
+            Some(instruction) => instruction,
+            None => return Status::Terminated,
+        };
+
+        match instruction {
+            Instruction::Snd(x) => {
+                let val = self.get_val(x);
+                self.sent += 1;
+                self.pos += 1;
+                Status::Sent(val)
+            }
+            Instruction::Rcv(x) => match self.queue.pop_front() {
+                Some(val) => {
+                    self.set(*x, val);
+                    self.pos += 1;
+                    Status::Running
+                }
+                None => Status::Blocked,
+            },
+            i => {
+                self.exec(i);
+                Status::Running
+            }
+        }
+    }
+}
+
+/// Runs several instances of one program concurrently.
+/// Every instance sends to the queue of the next one, the last one sends to the first.
+pub struct Duet<'a> {
+    program: &'a [Instruction],
+    pub instances: Vec<Instance>,
+}
+
+impl<'a> Duet<'a> {
+    /// Creates `count` instances, each with register `p` set to its id.
+    pub fn new(program: &'a [Instruction], count: usize) -> Self {
+        Duet {
+            program,
+            instances: (0..count).map(|id| Instance::new(id as i64)).collect(),
+        }
+    }
+
+    /// Runs all instances until they deadlock, i.e. every instance is either
+    /// blocked on an empty queue or has terminated.
+    pub fn run(&mut self) {
+        let count = self.instances.len();
+
+        loop {
+            let mut progressed = false;
+
+            for id in 0..count {
+                loop {
+                    match self.instances[id].step(self.program) {
+                        Status::Running => progressed = true,
+                        Status::Sent(val) => {
+                            progressed = true;
+                            self.instances[(id + 1) % count].queue.push_back(val);
+                        }
+                        Status::Blocked | Status::Terminated => break,
+                    }
+                }
+            }
+
+            if !progressed {
+                break;
+            }
+        }
+    }
+
+    /// Number of messages sent by the instance with `id`.
+    pub fn sent(&self, id: usize) -> usize {
+        self.instances[id].sent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(
+            parse("snd 1\njgz p -2\nset a b\nrcv c\nsub h -1\njnz 1 3"),
+            vec![
+                Instruction::Snd(Value::Int(1)),
+                Instruction::Jgz(Value::Reg('p'), Value::Int(-2)),
+                Instruction::Set('a', Value::Reg('b')),
+                Instruction::Rcv('c'),
+                Instruction::Sub('h', Value::Int(-1)),
+                Instruction::Jnz(Value::Int(1), Value::Int(3)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_deadlock() {
+        let program = parse("snd 1\nsnd 2\nsnd p\nrcv a\nrcv b\nrcv c\nrcv d");
+        let mut duet = Duet::new(&program, 2);
+        duet.run();
+
+        assert_eq!(duet.sent(0), 3);
+        assert_eq!(duet.sent(1), 3);
+        assert_eq!(duet.instances[0].get('c'), 1);
+        assert_eq!(duet.instances[1].get('c'), 0);
+        assert!(duet.instances.iter().all(|i| i.queue.is_empty()));
+    }
+}
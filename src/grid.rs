@@ -1,53 +1,739 @@
-use std::fmt::Debug;
+use hashbrown::hash_map::Entry;
+use hashbrown::HashMap;
+use std::error::Error;
+use std::fmt::{self, Debug, Display};
+use std::ops::{Add, AddAssign, Index, IndexMut, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
 
 /// A point describes a location `x, y` in a grid with two axis.
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
 pub struct Point(pub usize, pub usize);
 
+/// Which cells count as adjacent: the four sharing an edge, or all eight around a cell.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Connectivity {
+    Four,
+    Eight,
+}
+
+// orthogonal offsets come first, so four-connectivity is a prefix of eight-connectivity.
+const OFFSETS: [(isize, isize); 8] = [
+    (0, -1),
+    (1, 0),
+    (0, 1),
+    (-1, 0),
+    (1, -1),
+    (1, 1),
+    (-1, 1),
+    (-1, -1),
+];
+
+/// An iterator over the neighbors of a point which lie within inclusive bounds. It does not allocate.
+#[derive(Clone, Debug)]
+pub struct Neighbors {
+    center: Point,
+    min: Point,
+    max: Point,
+    offsets: &'static [(isize, isize)],
+}
+
+impl Neighbors {
+    pub fn new(center: Point, min: Point, max: Point, connectivity: Connectivity) -> Self {
+        let offsets = match connectivity {
+            Connectivity::Four => &OFFSETS[..4],
+            Connectivity::Eight => &OFFSETS[..],
+        };
+
+        Neighbors {
+            center,
+            min,
+            max,
+            offsets,
+        }
+    }
+
+    /// An iterator that yields nothing, e.g. for an empty grid.
+    pub fn empty(center: Point) -> Self {
+        Neighbors {
+            center,
+            min: center,
+            max: center,
+            offsets: &[],
+        }
+    }
+}
+
+impl Iterator for Neighbors {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Point> {
+        while let Some((&(dx, dy), rest)) = self.offsets.split_first() {
+            self.offsets = rest;
+
+            let x = self.center.0.checked_add_signed(dx);
+            let y = self.center.1.checked_add_signed(dy);
+
+            if let (Some(x), Some(y)) = (x, y) {
+                if (self.min.0..=self.max.0).contains(&x) && (self.min.1..=self.max.1).contains(&y)
+                {
+                    return Some(Point(x, y));
+                }
+            }
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.offsets.len()))
+    }
+}
+
 impl Point {
     /// Get all neighbors for a point in a grid, respecting the boundaries of the input.
-    pub fn neighbors(self, max_x: usize, max_y: usize, include_diagonals: bool) -> Vec<Point> {
-        let mut neighbors: Vec<Point> = Vec::new();
-        let Point(x, y) = self;
+    pub fn neighbors(self, max_x: usize, max_y: usize, include_diagonals: bool) -> Neighbors {
+        let connectivity = if include_diagonals {
+            Connectivity::Eight
+        } else {
+            Connectivity::Four
+        };
 
-        let bound_top = y == 0;
-        let bound_left = x == 0;
+        self.neighbors_within(Point(0, 0), Point(max_x, max_y), connectivity)
+    }
 
-        let bound_bottom = y == max_y;
-        let bound_right = x == max_x;
+    /// Neighbors which lie between `min` and `max`, both inclusive.
+    pub fn neighbors_within(self, min: Point, max: Point, connectivity: Connectivity) -> Neighbors {
+        Neighbors::new(self, min, max, connectivity)
+    }
+}
 
-        if !bound_top {
-            neighbors.push(Point(x, y - 1));
+#[derive(Debug, PartialEq, Eq)]
+pub enum GridError {
+    /// The row on the given line is not as wide as the rows before it.
+    Ragged { line: usize },
+}
 
-            if include_diagonals && !bound_left {
-                neighbors.push(Point(x - 1, y - 1));
+impl Display for GridError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GridError::Ragged { line } => {
+                write!(
+                    f,
+                    "row on line {} differs in width from the first row",
+                    line
+                )
             }
+        }
+    }
+}
+
+impl Error for GridError {}
+
+/// A dense rectangular grid, stored row by row. `Point(x, y)` addresses column `x` of row `y`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![value; width * height],
+        }
+    }
 
-            if include_diagonals && !bound_right {
-                neighbors.push(Point(x + 1, y - 1));
+    /// Creates a grid where each cell is initialized by calling `f` with its point.
+    pub fn from_fn(width: usize, height: usize, mut f: impl FnMut(Point) -> T) -> Self {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| Point(x, y)))
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses one row per line, mapping each character with `f`. All rows must be equally wide.
+    pub fn parse(input: &str, mut f: impl FnMut(char) -> T) -> Result<Self, GridError> {
+        let mut width = None;
+        let mut cells = Vec::new();
+
+        for (i, line) in input.lines().enumerate() {
+            let len = cells.len();
+            cells.extend(line.chars().map(&mut f));
+
+            match width {
+                None => width = Some(cells.len()),
+                Some(width) if cells.len() - len != width => {
+                    return Err(GridError::Ragged { line: i + 1 })
+                }
+                _ => {}
             }
         }
 
-        if !bound_bottom {
-            neighbors.push(Point(x, y + 1));
+        let width = width.unwrap_or(0);
 
-            if include_diagonals && !bound_left {
-                neighbors.push(Point(x - 1, y + 1));
+        Ok(Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        })
+    }
+
+    /// Like `parse`, but rows shorter than the widest one are padded with `fill`.
+    /// Useful for inputs where trailing whitespace may have been stripped.
+    pub fn parse_padded(input: &str, fill: char, mut f: impl FnMut(char) -> T) -> Self {
+        let width = input.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+
+        let cells: Vec<T> = input
+            .lines()
+            .flat_map(|l| {
+                let len = l.chars().count();
+                l.chars().chain(std::iter::repeat_n(fill, width - len))
+            })
+            .map(&mut f)
+            .collect();
+
+        Grid {
+            width,
+            height: cells.len().checked_div(width).unwrap_or(0),
+            cells,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, point: Point) -> bool {
+        point.0 < self.width && point.1 < self.height
+    }
+
+    pub fn get(&self, point: Point) -> Option<&T> {
+        if self.contains(point) {
+            self.cells.get(point.1 * self.width + point.0)
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, point: Point) -> Option<&mut T> {
+        if self.contains(point) {
+            self.cells.get_mut(point.1 * self.width + point.0)
+        } else {
+            None
+        }
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} is out of bounds", x);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// All points of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point(x, y)))
+    }
+
+    /// All cells together with their point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point> {
+        self.iter().find(|(_, v)| predicate(v)).map(|(p, _)| p)
+    }
+
+    /// Neighbors of `point` which lie within the grid.
+    pub fn neighbors(&self, point: Point, include_diagonals: bool) -> Neighbors {
+        if self.width == 0 || self.height == 0 {
+            return Neighbors::empty(point);
+        }
+
+        point.neighbors(self.width - 1, self.height - 1, include_diagonals)
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+}
+
+impl<T> Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, point: Point) -> &T {
+        self.get(point)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", point))
+    }
+}
+
+impl<T> IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, point: Point) -> &mut T {
+        self.get_mut(point)
+            .unwrap_or_else(|| panic!("{:?} is out of bounds", point))
+    }
+}
+
+impl FromStr for Grid<char> {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Grid::parse(s, |c| c)
+    }
+}
+
+/// Prints one line per row, use `{:#}` to separate the cells by a space.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
             }
 
-            if include_diagonals && !bound_right {
-                neighbors.push(Point(x + 1, y + 1));
+            for (x, cell) in row.iter().enumerate() {
+                if f.alternate() && x > 0 {
+                    write!(f, " ")?;
+                }
+
+                write!(f, "{}", cell)?;
             }
         }
 
-        if !bound_left {
-            neighbors.push(Point(x - 1, y));
+        Ok(())
+    }
+}
+
+/// A location `x, y` on an unbounded plane. `y` grows downwards, like the rows of a [Grid].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Coord(pub i32, pub i32);
+
+impl Coord {
+    pub const ORIGIN: Coord = Coord(0, 0);
+
+    /// Sum of the distances along both axis.
+    pub fn manhattan(self, other: Coord) -> u32 {
+        (self.0 - other.0).unsigned_abs() + (self.1 - other.1).unsigned_abs()
+    }
+
+    /// Largest distance along either axis, i.e. the number of king moves between two coordinates.
+    pub fn chebyshev(self, other: Coord) -> u32 {
+        (self.0 - other.0)
+            .unsigned_abs()
+            .max((self.1 - other.1).unsigned_abs())
+    }
+
+    /// The four adjacent coordinates, or eight when `include_diagonals` is set. It does not allocate.
+    pub fn neighbors(self, include_diagonals: bool) -> impl Iterator<Item = Coord> {
+        let offsets = if include_diagonals {
+            &OFFSETS[..]
+        } else {
+            &OFFSETS[..4]
+        };
+
+        offsets
+            .iter()
+            .map(move |&(dx, dy)| self + Coord(dx as i32, dy as i32))
+    }
+}
+
+impl Display for Coord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{}", self.0, self.1)
+    }
+}
+
+impl From<Point> for Coord {
+    fn from(Point(x, y): Point) -> Self {
+        Coord(x as i32, y as i32)
+    }
+}
+
+impl Add for Coord {
+    type Output = Coord;
+
+    fn add(self, other: Coord) -> Coord {
+        Coord(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+impl Sub for Coord {
+    type Output = Coord;
+
+    fn sub(self, other: Coord) -> Coord {
+        Coord(self.0 - other.0, self.1 - other.1)
+    }
+}
+
+impl Mul<i32> for Coord {
+    type Output = Coord;
+
+    fn mul(self, factor: i32) -> Coord {
+        Coord(self.0 * factor, self.1 * factor)
+    }
+}
+
+impl Neg for Coord {
+    type Output = Coord;
+
+    fn neg(self) -> Coord {
+        Coord(-self.0, -self.1)
+    }
+}
+
+impl AddAssign for Coord {
+    fn add_assign(&mut self, other: Coord) {
+        *self = *self + other;
+    }
+}
+
+impl SubAssign for Coord {
+    fn sub_assign(&mut self, other: Coord) {
+        *self = *self - other;
+    }
+}
+
+impl TryFrom<Coord> for Point {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(Coord(x, y): Coord) -> Result<Self, Self::Error> {
+        Ok(Point(usize::try_from(x)?, usize::try_from(y)?))
+    }
+}
+
+/// One of the four headings on a grid, in clockwise order.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    fn rotate(self, quarter_turns: usize) -> Direction {
+        Direction::ALL[(self as usize + quarter_turns) % 4]
+    }
+
+    pub fn turn_left(self) -> Direction {
+        self.rotate(3)
+    }
+
+    pub fn turn_right(self) -> Direction {
+        self.rotate(1)
+    }
+
+    pub fn reverse(self) -> Direction {
+        self.rotate(2)
+    }
+
+    /// The change of a coordinate when taking one step in this direction.
+    pub fn offset(self) -> Coord {
+        match self {
+            Direction::Up => Coord(0, -1),
+            Direction::Right => Coord(1, 0),
+            Direction::Down => Coord(0, 1),
+            Direction::Left => Coord(-1, 0),
+        }
+    }
+
+    pub fn step(self, coord: Coord) -> Coord {
+        coord + self.offset()
+    }
+}
+
+/// A position together with the direction it is heading in.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Walker {
+    pub position: Coord,
+    pub heading: Direction,
+}
+
+impl Walker {
+    pub fn new(position: Coord, heading: Direction) -> Self {
+        Walker { position, heading }
+    }
+
+    /// The coordinate one step ahead, without moving.
+    pub fn ahead(&self) -> Coord {
+        self.heading.step(self.position)
+    }
+
+    /// Takes one step in the current heading and returns the new position.
+    pub fn advance(&mut self) -> Coord {
+        self.position = self.ahead();
+        self.position
+    }
+
+    pub fn turn_left(&mut self) {
+        self.heading = self.heading.turn_left();
+    }
+
+    pub fn turn_right(&mut self) {
+        self.heading = self.heading.turn_right();
+    }
+
+    pub fn reverse(&mut self) {
+        self.heading = self.heading.reverse();
+    }
+}
+
+fn extend_bounds(bounds: &mut Option<(Coord, Coord)>, coord: Coord) {
+    *bounds = Some(match *bounds {
+        None => (coord, coord),
+        Some((min, max)) => (
+            Coord(min.0.min(coord.0), min.1.min(coord.1)),
+            Coord(max.0.max(coord.0), max.1.max(coord.1)),
+        ),
+    });
+}
+
+/// A sparse grid on an unbounded plane which only stores the cells that were written.
+/// It tracks the bounding box of every cell written so far, which is kept when cells are removed.
+#[derive(Clone, Debug)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Coord, T>,
+    bounds: Option<(Coord, Coord)>,
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
         }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    pub fn get(&self, coord: Coord) -> Option<&T> {
+        self.cells.get(&coord)
+    }
 
-        if !bound_right {
-            neighbors.push(Point(x + 1, y));
+    pub fn get_mut(&mut self, coord: Coord) -> Option<&mut T> {
+        self.cells.get_mut(&coord)
+    }
+
+    pub fn contains(&self, coord: Coord) -> bool {
+        self.cells.contains_key(&coord)
+    }
+
+    pub fn insert(&mut self, coord: Coord, value: T) -> Option<T> {
+        extend_bounds(&mut self.bounds, coord);
+        self.cells.insert(coord, value)
+    }
+
+    /// The cell at `coord`, which is initialized by calling `f` if it was not written yet.
+    pub fn get_or_insert_with(&mut self, coord: Coord, f: impl FnOnce() -> T) -> &mut T {
+        match self.cells.entry(coord) {
+            Entry::Occupied(entry) => entry.into_mut(),
+            Entry::Vacant(entry) => {
+                extend_bounds(&mut self.bounds, coord);
+                entry.insert(f())
+            }
         }
+    }
+
+    pub fn remove(&mut self, coord: Coord) -> Option<T> {
+        self.cells.remove(&coord)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// All written cells, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (Coord, &T)> {
+        self.cells.iter().map(|(c, v)| (*c, v))
+    }
+
+    /// The smallest and largest coordinate of the bounding box, `None` until a cell is written.
+    pub fn bounds(&self) -> Option<(Coord, Coord)> {
+        self.bounds
+    }
+
+    /// Renders the bounding box row by row, `f` receives `None` for cells that were not written.
+    pub fn render(&self, f: impl Fn(Option<&T>) -> char) -> String {
+        let (min, max) = match self.bounds {
+            Some(bounds) => bounds,
+            None => return String::new(),
+        };
+
+        (min.1..=max.1)
+            .map(|y| {
+                (min.0..=max.0)
+                    .map(|x| f(self.get(Coord(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> FromIterator<(Coord, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Coord, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+
+        for (coord, value) in iter {
+            grid.insert(coord, value);
+        }
+
+        grid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let grid: Grid<char> = "ab\ncd\nef".parse().unwrap();
+
+        assert_eq!((grid.width(), grid.height()), (2, 3));
+        assert_eq!(grid[Point(1, 2)], 'f');
+        assert_eq!(grid.get(Point(2, 0)), None);
+        assert_eq!(grid.row(1), &['c', 'd']);
+        assert_eq!(grid.column(0).collect::<String>(), "ace");
+        assert_eq!(grid.position(|&c| c == 'd'), Some(Point(1, 1)));
+        assert_eq!(grid.to_string(), "ab\ncd\nef");
+
+        assert_eq!(
+            "ab\nc".parse::<Grid<char>>(),
+            Err(GridError::Ragged { line: 2 })
+        );
+
+        let padded = Grid::parse_padded(" |\n|", '.', |c| c);
+        assert_eq!(padded.to_string(), " |\n|.");
+    }
+
+    #[test]
+    fn test_grid() {
+        let mut grid = Grid::from_fn(3, 2, |Point(x, y)| x + y);
+        grid[Point(0, 0)] = 9;
+
+        assert_eq!(format!("{:#}", grid), "9 1 2\n1 2 3");
+        assert_eq!(
+            grid.map(|v| v % 2 == 0).iter().filter(|(_, &v)| v).count(),
+            2
+        );
+        assert_eq!(
+            grid.neighbors(Point(2, 1), false).collect::<Vec<_>>(),
+            vec![Point(2, 0), Point(1, 1)]
+        );
+        assert_eq!(grid.neighbors(Point(0, 0), true).count(), 3);
+    }
+
+    #[test]
+    fn test_coord() {
+        let a = Coord(1, -2);
+        let b = Coord(-3, 4);
+
+        assert_eq!(a + b, Coord(-2, 2));
+        assert_eq!(a - b, Coord(4, -6));
+        assert_eq!(-a * 2, Coord(-2, 4));
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(Coord::ORIGIN.neighbors(true).count(), 8);
+        assert_eq!(
+            Coord(1, 1).neighbors(false).collect::<Vec<_>>(),
+            vec![Coord(1, 0), Coord(2, 1), Coord(1, 2), Coord(0, 1)]
+        );
+        assert_eq!(Coord::from(Point(2, 3)), Coord(2, 3));
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<char> = [(Coord(-1, 0), '#'), (Coord(1, 1), '#')]
+            .into_iter()
+            .collect();
+
+        assert_eq!(grid.bounds(), Some((Coord(-1, 0), Coord(1, 1))));
+        assert_eq!(grid.render(|c| *c.unwrap_or(&'.')), "#..\n..#");
+
+        *grid.get_or_insert_with(Coord(0, -2), || '.') = 'x';
+        assert_eq!(grid.get(Coord(0, -2)), Some(&'x'));
+        assert_eq!(grid.len(), 3);
+
+        grid.remove(Coord(0, -2));
+        assert_eq!(grid.bounds(), Some((Coord(-1, -2), Coord(1, 1))));
+        assert!(!grid.contains(Coord(0, -2)));
+    }
+
+    #[test]
+    fn test_walker() {
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Right.reverse(), Direction::Left);
+
+        let mut walker = Walker::new(Coord::ORIGIN, Direction::Up);
+        walker.advance();
+        walker.turn_right();
+        assert_eq!(walker.ahead(), Coord(1, -1));
+        walker.reverse();
+        walker.advance();
+        assert_eq!(walker, Walker::new(Coord(-1, -1), Direction::Left));
+
+        assert_eq!(Point::try_from(Coord(2, 1)), Ok(Point(2, 1)));
+        assert!(Point::try_from(Coord(-1, 0)).is_err());
+    }
 
-        neighbors
+    #[test]
+    fn test_neighbors() {
+        assert_eq!(
+            Point(1, 1).neighbors(2, 2, false).collect::<Vec<_>>(),
+            vec![Point(1, 0), Point(2, 1), Point(1, 2), Point(0, 1)]
+        );
+        assert_eq!(Point(1, 1).neighbors(2, 2, true).count(), 8);
+        assert_eq!(Point(0, 0).neighbors(2, 2, true).count(), 3);
+        assert_eq!(
+            Point(3, 3)
+                .neighbors_within(Point(3, 2), Point(4, 3), Connectivity::Eight)
+                .collect::<Vec<_>>(),
+            vec![Point(3, 2), Point(4, 3), Point(4, 2)]
+        );
+        assert_eq!(Neighbors::empty(Point(0, 0)).count(), 0);
     }
 }
@@ -0,0 +1,352 @@
+//! Hexagonal grids in cube coordinates, see https://www.redblobgames.com/grids/hexagons/
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::ops::{Add, AddAssign, Mul, Sub};
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum HexError {
+    /// A step that is not one of the six directions of the layout.
+    Direction(String),
+}
+
+impl Display for HexError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HexError::Direction(s) => write!(f, "invalid direction \"{}\"", s),
+        }
+    }
+}
+
+impl Error for HexError {}
+
+/// A hex in cube coordinates, the three components always sum up to zero.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Cube {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+}
+
+/// A hex in axial coordinates, which drop the redundant `s` of [Cube].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Axial {
+    pub q: i32,
+    pub r: i32,
+}
+
+// the six unit vectors, counter-clockwise starting at `q + 1`.
+const OFFSETS: [Cube; 6] = [
+    Cube::new(1, 0),
+    Cube::new(1, -1),
+    Cube::new(0, -1),
+    Cube::new(-1, 0),
+    Cube::new(-1, 1),
+    Cube::new(0, 1),
+];
+
+impl Cube {
+    pub const ORIGIN: Cube = Cube::new(0, 0);
+
+    pub const fn new(q: i32, r: i32) -> Self {
+        Cube { q, r, s: -q - r }
+    }
+
+    pub fn step(self, offset: impl Into<Cube>) -> Cube {
+        self + offset.into()
+    }
+
+    /// Number of steps between two hexes.
+    pub fn distance(self, other: Cube) -> u32 {
+        let d = self - other;
+        (d.q.unsigned_abs() + d.r.unsigned_abs() + d.s.unsigned_abs()) / 2
+    }
+
+    /// The six adjacent hexes.
+    pub fn neighbors(self) -> impl Iterator<Item = Cube> {
+        OFFSETS.into_iter().map(move |offset| self + offset)
+    }
+
+    /// All hexes at exactly `radius` steps, walking around the ring.
+    pub fn ring(self, radius: u32) -> Vec<Cube> {
+        if radius == 0 {
+            return vec![self];
+        }
+
+        let radius = radius as i32;
+        let mut hex = self + OFFSETS[4] * radius;
+        let mut ring = Vec::with_capacity(6 * radius as usize);
+
+        for offset in OFFSETS {
+            for _ in 0..radius {
+                ring.push(hex);
+                hex += offset;
+            }
+        }
+
+        ring
+    }
+
+    /// All hexes at most `radius` steps away, ordered by distance.
+    pub fn spiral(self, radius: u32) -> Vec<Cube> {
+        (0..=radius).flat_map(|r| self.ring(r)).collect()
+    }
+
+    /// The hexes on a straight line between both ends, including them.
+    pub fn line(self, to: Cube) -> Vec<Cube> {
+        let n = self.distance(to);
+
+        // nudging the start keeps points that fall exactly on an edge on the same side.
+        let (q, r, s) = (
+            self.q as f64 + 1e-6,
+            self.r as f64 + 1e-6,
+            self.s as f64 - 2e-6,
+        );
+
+        (0..=n)
+            .map(|i| {
+                let t = if n == 0 { 0.0 } else { i as f64 / n as f64 };
+                round(
+                    q + (to.q as f64 - q) * t,
+                    r + (to.r as f64 - r) * t,
+                    s + (to.s as f64 - s) * t,
+                )
+            })
+            .collect()
+    }
+}
+
+// rounds fractional cube coordinates to the hex containing them.
+fn round(q: f64, r: f64, s: f64) -> Cube {
+    let (rq, rr, rs) = (q.round(), r.round(), s.round());
+    let (dq, dr, ds) = ((rq - q).abs(), (rr - r).abs(), (rs - s).abs());
+
+    if dq > dr && dq > ds {
+        Cube::new(-(rr as i32) - rs as i32, rr as i32)
+    } else if dr > ds {
+        Cube::new(rq as i32, -(rq as i32) - rs as i32)
+    } else {
+        Cube::new(rq as i32, rr as i32)
+    }
+}
+
+impl Display for Cube {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{},{},{}", self.q, self.r, self.s)
+    }
+}
+
+impl From<Axial> for Cube {
+    fn from(Axial { q, r }: Axial) -> Self {
+        Cube::new(q, r)
+    }
+}
+
+impl From<Cube> for Axial {
+    fn from(Cube { q, r, .. }: Cube) -> Self {
+        Axial { q, r }
+    }
+}
+
+impl Add for Cube {
+    type Output = Cube;
+
+    fn add(self, other: Cube) -> Cube {
+        Cube::new(self.q + other.q, self.r + other.r)
+    }
+}
+
+impl Sub for Cube {
+    type Output = Cube;
+
+    fn sub(self, other: Cube) -> Cube {
+        Cube::new(self.q - other.q, self.r - other.r)
+    }
+}
+
+impl Mul<i32> for Cube {
+    type Output = Cube;
+
+    fn mul(self, factor: i32) -> Cube {
+        Cube::new(self.q * factor, self.r * factor)
+    }
+}
+
+impl AddAssign for Cube {
+    fn add_assign(&mut self, other: Cube) {
+        *self = *self + other;
+    }
+}
+
+/// Directions on a grid of hexes with flat tops, which are arranged in columns.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    N,
+    NE,
+    SE,
+    S,
+    SW,
+    NW,
+}
+
+impl Direction {
+    pub const ALL: [Direction; 6] = [
+        Direction::N,
+        Direction::NE,
+        Direction::SE,
+        Direction::S,
+        Direction::SW,
+        Direction::NW,
+    ];
+}
+
+impl From<Direction> for Cube {
+    fn from(direction: Direction) -> Self {
+        match direction {
+            Direction::N => Cube::new(0, -1),
+            Direction::NE => Cube::new(1, -1),
+            Direction::SE => Cube::new(1, 0),
+            Direction::S => Cube::new(0, 1),
+            Direction::SW => Cube::new(-1, 1),
+            Direction::NW => Cube::new(-1, 0),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "n" => Ok(Direction::N),
+            "ne" => Ok(Direction::NE),
+            "se" => Ok(Direction::SE),
+            "s" => Ok(Direction::S),
+            "sw" => Ok(Direction::SW),
+            "nw" => Ok(Direction::NW),
+            s => Err(HexError::Direction(s.to_string())),
+        }
+    }
+}
+
+/// Directions on a grid of hexes with pointy tops, which are arranged in rows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum PointyDirection {
+    E,
+    NE,
+    NW,
+    W,
+    SW,
+    SE,
+}
+
+impl PointyDirection {
+    pub const ALL: [PointyDirection; 6] = [
+        PointyDirection::E,
+        PointyDirection::NE,
+        PointyDirection::NW,
+        PointyDirection::W,
+        PointyDirection::SW,
+        PointyDirection::SE,
+    ];
+}
+
+impl From<PointyDirection> for Cube {
+    fn from(direction: PointyDirection) -> Self {
+        match direction {
+            PointyDirection::E => Cube::new(1, 0),
+            PointyDirection::NE => Cube::new(1, -1),
+            PointyDirection::NW => Cube::new(0, -1),
+            PointyDirection::W => Cube::new(-1, 0),
+            PointyDirection::SW => Cube::new(-1, 1),
+            PointyDirection::SE => Cube::new(0, 1),
+        }
+    }
+}
+
+impl FromStr for PointyDirection {
+    type Err = HexError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "e" => Ok(PointyDirection::E),
+            "ne" => Ok(PointyDirection::NE),
+            "nw" => Ok(PointyDirection::NW),
+            "w" => Ok(PointyDirection::W),
+            "sw" => Ok(PointyDirection::SW),
+            "se" => Ok(PointyDirection::SE),
+            s => Err(HexError::Direction(s.to_string())),
+        }
+    }
+}
+
+/// Parses a comma-separated list of steps, such as `ne,ne,s`.
+pub fn parse_path<D: FromStr<Err = HexError>>(input: &str) -> Result<Vec<D>, HexError> {
+    input
+        .trim()
+        .split(',')
+        .filter(|s| !s.is_empty())
+        .map(|s| s.trim().parse())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_path() {
+        assert_eq!(
+            parse_path("ne,s,nw\n"),
+            Ok(vec![Direction::NE, Direction::S, Direction::NW])
+        );
+        assert_eq!(
+            parse_path::<PointyDirection>("e,w"),
+            Ok(vec![PointyDirection::E, PointyDirection::W])
+        );
+        assert_eq!(
+            parse_path::<Direction>("n,e"),
+            Err(HexError::Direction("e".to_string()))
+        );
+        assert_eq!(parse_path::<Direction>(""), Ok(vec![]));
+    }
+
+    #[test]
+    fn test_cube() {
+        let hex = Cube::ORIGIN
+            .step(Direction::NE)
+            .step(Direction::NE)
+            .step(Direction::S);
+
+        assert_eq!(hex, Cube::new(2, -1));
+        assert_eq!(hex.distance(Cube::ORIGIN), 2);
+        assert_eq!(Cube::from(Axial::from(hex)), hex);
+        assert!(hex.neighbors().all(|n| n.distance(hex) == 1));
+
+        // every direction of both layouts leads to a neighbor.
+        let mut offsets: Vec<Cube> = Direction::ALL.into_iter().map(Cube::from).collect();
+        offsets.extend(PointyDirection::ALL.into_iter().map(Cube::from));
+        assert!(offsets.iter().all(|o| o.distance(Cube::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn test_ring() {
+        assert_eq!(Cube::ORIGIN.ring(0), vec![Cube::ORIGIN]);
+
+        let ring = Cube::new(1, 1).ring(2);
+        assert_eq!(ring.len(), 12);
+        assert!(ring.iter().all(|h| h.distance(Cube::new(1, 1)) == 2));
+        assert_eq!(Cube::ORIGIN.spiral(2).len(), 19);
+    }
+
+    #[test]
+    fn test_line() {
+        let to = Cube::new(3, -1);
+        let line = Cube::ORIGIN.line(to);
+
+        assert_eq!(line.len(), 4);
+        assert_eq!((line[0], line[3]), (Cube::ORIGIN, to));
+        assert!(line.windows(2).all(|w| w[0].distance(w[1]) == 1));
+        assert_eq!(Cube::ORIGIN.line(Cube::ORIGIN), vec![Cube::ORIGIN]);
+    }
+}
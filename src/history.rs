@@ -0,0 +1,252 @@
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use crate::Record;
+
+const HEADER: &str = "run,profile,day,part,nanos";
+
+/// The build profile a run was timed with. Only runs of the same profile are compared.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Profile {
+    Debug,
+    Release,
+}
+
+impl Profile {
+    /// The profile of the running binary.
+    pub fn current() -> Profile {
+        if cfg!(debug_assertions) {
+            Profile::Debug
+        } else {
+            Profile::Release
+        }
+    }
+
+    fn parse(s: &str) -> Option<Profile> {
+        match s {
+            "debug" => Some(Profile::Debug),
+            "release" => Some(Profile::Release),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Profile {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Profile::Debug => write!(f, "debug"),
+            Profile::Release => write!(f, "release"),
+        }
+    }
+}
+
+/// Timing of one part in one run of the all-days runner.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Entry {
+    pub run: u64,
+    pub profile: Profile,
+    pub day: u8,
+    pub part: u8,
+    pub nanos: u128,
+}
+
+impl Entry {
+    pub fn from_record(run: u64, profile: Profile, record: &Record) -> Entry {
+        Entry {
+            run,
+            profile,
+            day: record.day,
+            part: record.part,
+            nanos: record.nanos,
+        }
+    }
+
+    fn parse(line: &str) -> Option<Entry> {
+        let mut fields = line.split(',');
+
+        Some(Entry {
+            run: fields.next()?.parse().ok()?,
+            profile: Profile::parse(fields.next()?)?,
+            day: fields.next()?.parse().ok()?,
+            part: fields.next()?.parse().ok()?,
+            nanos: fields.next()?.parse().ok()?,
+        })
+    }
+}
+
+impl Display for Entry {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{},{},{},{},{}",
+            self.run, self.profile, self.day, self.part, self.nanos
+        )
+    }
+}
+
+/// Reads all entries from a history file. A missing file is an empty history,
+/// lines without a profile from older versions are skipped.
+pub fn load(path: &Path) -> io::Result<Vec<Entry>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(contents.lines().filter_map(Entry::parse).collect()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(vec![]),
+        Err(e) => Err(e),
+    }
+}
+
+pub fn append(path: &Path, entries: &[Entry]) -> io::Result<()> {
+    let is_new = !path.exists();
+    let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+
+    if is_new {
+        writeln!(file, "{}", HEADER)?;
+    }
+
+    for entry in entries {
+        writeln!(file, "{}", entry)?;
+    }
+
+    Ok(())
+}
+
+/// Id for the next run, one more than the highest id in the history.
+pub fn next_run(entries: &[Entry]) -> u64 {
+    entries.iter().map(|e| e.run + 1).max().unwrap_or(1)
+}
+
+/// Entries of the most recent run with `profile` in a history.
+pub fn latest_run(entries: &[Entry], profile: Profile) -> Vec<Entry> {
+    let entries: Vec<&Entry> = entries.iter().filter(|e| e.profile == profile).collect();
+
+    match entries.iter().map(|e| e.run).max() {
+        Some(run) => entries
+            .into_iter()
+            .filter(|e| e.run == run)
+            .cloned()
+            .collect(),
+        None => vec![],
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Change {
+    pub day: u8,
+    pub part: u8,
+    pub before: u128,
+    pub after: u128,
+}
+
+impl Change {
+    /// Relative change in percent, positive if the part got slower.
+    pub fn percent(&self) -> f64 {
+        (self.after as f64 - self.before as f64) / self.before as f64 * 100_f64
+    }
+}
+
+impl Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let percent = self.percent();
+
+        write!(
+            f,
+            "| {:>3} | {:>4} | {:>10.2?} | {:>10.2?} | {:>+8.1}% {} |",
+            self.day,
+            self.part,
+            Duration::from_nanos(self.before as u64),
+            Duration::from_nanos(self.after as u64),
+            percent,
+            if percent > 0_f64 { "slower" } else { "faster" }
+        )
+    }
+}
+
+/// Parts which got slower or faster by more than `threshold` percent between two runs.
+pub fn compare(previous: &[Entry], current: &[Entry], threshold: f64) -> Vec<Change> {
+    current
+        .iter()
+        .filter_map(|entry| {
+            let before = previous
+                .iter()
+                .find(|p| p.day == entry.day && p.part == entry.part)?;
+
+            Some(Change {
+                day: entry.day,
+                part: entry.part,
+                before: before.nanos,
+                after: entry.nanos,
+            })
+        })
+        .filter(|change| change.before > 0 && change.percent().abs() > threshold)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(run: u64, day: u8, part: u8, nanos: u128) -> Entry {
+        Entry {
+            run,
+            profile: Profile::Release,
+            day,
+            part,
+            nanos,
+        }
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.csv", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        assert_eq!(load(&path).unwrap(), vec![]);
+
+        append(&path, &[entry(1, 1, 1, 100), entry(1, 1, 2, 200)]).unwrap();
+        append(&path, &[entry(2, 1, 1, 150)]).unwrap();
+        append(
+            &path,
+            &[Entry {
+                profile: Profile::Debug,
+                ..entry(3, 1, 1, 1500)
+            }],
+        )
+        .unwrap();
+
+        let entries = load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(entries.len(), 4);
+        assert_eq!(
+            latest_run(&entries, Profile::Release),
+            vec![entry(2, 1, 1, 150)]
+        );
+        assert_eq!(latest_run(&entries, Profile::Debug).len(), 1);
+        assert_eq!(next_run(&entries), 4);
+        assert_eq!(next_run(&[]), 1);
+        assert_eq!(Entry::parse("1,1,1,100"), None);
+    }
+
+    #[test]
+    fn test_compare() {
+        let previous = [
+            entry(1, 1, 1, 100),
+            entry(1, 1, 2, 100),
+            entry(1, 2, 1, 100),
+        ];
+        let current = [
+            entry(2, 1, 1, 150),
+            entry(2, 1, 2, 105),
+            entry(2, 2, 1, 50),
+            entry(2, 3, 1, 10),
+        ];
+
+        let changes = compare(&previous, &current, 10_f64);
+
+        assert_eq!(changes.len(), 2);
+        assert_eq!(changes[0].percent(), 50_f64);
+        assert_eq!(changes[1].percent(), -50_f64);
+    }
+}
@@ -0,0 +1,265 @@
+use std::env;
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process;
+
+/// Overrides the directory which contains the `inputs`, `examples` and `answers` folders.
+pub const INPUT_ROOT_ENV: &str = "AOC_INPUT_ROOT";
+
+#[derive(Debug)]
+pub enum InputError {
+    NotFound(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+    Stdin(io::Error),
+    Args(String),
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            InputError::NotFound(path) => {
+                write!(f, "input file \"{}\" does not exist", path.display())
+            }
+            InputError::Io { path, source } => {
+                write!(
+                    f,
+                    "could not read input file \"{}\": {}",
+                    path.display(),
+                    source
+                )
+            }
+            InputError::Stdin(source) => write!(f, "could not read input from stdin: {}", source),
+            InputError::Args(message) => write!(f, "invalid arguments: {}", message),
+        }
+    }
+}
+
+impl Error for InputError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            InputError::Io { source, .. } | InputError::Stdin(source) => Some(source),
+            _ => None,
+        }
+    }
+}
+
+/// Where the input of a solution is read from.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Source {
+    /// `<root>/<folder>/<NN>.txt`, with the default root if `None`.
+    Day {
+        root: Option<PathBuf>,
+        folder: String,
+        day: u8,
+    },
+    File(PathBuf),
+    Stdin,
+}
+
+impl Source {
+    /// Reads the source from command line arguments, falling back to the puzzle input of `day`.
+    /// `--input <path>` reads a file (`-` for stdin), `--input-root <dir>` changes the root directory.
+    /// Other arguments are left to the solution, e.g. `--render` of day 14, and are ignored here.
+    pub fn from_args(args: &[String], day: u8) -> Result<Source, InputError> {
+        let mut input = None;
+        let mut root = None;
+        let mut args = args.iter();
+
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| InputError::Args(format!("`{}` requires a value", arg)))
+            };
+
+            match arg.as_str() {
+                "--input" => input = Some(value()?),
+                "--input-root" => root = Some(PathBuf::from(value()?)),
+                _ => {}
+            }
+        }
+
+        match (input.map(|i| i.as_str()), root) {
+            (Some(_), Some(_)) => Err(InputError::Args(
+                "`--input` and `--input-root` cannot be combined".to_string(),
+            )),
+            (Some("-"), None) => Ok(Source::Stdin),
+            (Some(path), None) => Ok(Source::File(PathBuf::from(path))),
+            (None, root) => Ok(Source::Day {
+                root,
+                folder: "inputs".to_string(),
+                day,
+            }),
+        }
+    }
+
+    /// The source configured by the arguments of the running binary.
+    pub fn from_env(day: u8) -> Result<Source, InputError> {
+        let args: Vec<String> = env::args().skip(1).collect();
+        Source::from_args(&args, day)
+    }
+
+    pub fn read(&self) -> Result<String, InputError> {
+        match self {
+            Source::Day { root, folder, day } => {
+                let root = root.clone().unwrap_or_else(input_root);
+                read_path(&root.join(folder).join(file_name(*day)))
+            }
+            Source::File(path) => read_path(path),
+            Source::Stdin => {
+                let mut contents = String::new();
+                io::stdin()
+                    .read_to_string(&mut contents)
+                    .map_err(InputError::Stdin)?;
+                Ok(normalize(&contents))
+            }
+        }
+    }
+}
+
+pub fn file_name(day: u8) -> String {
+    format!("{:02}.txt", day)
+}
+
+/// Name of an example variant of a day, e.g. `07-2.txt` for the example of part two.
+pub fn example_file_name(day: u8, variant: &str) -> String {
+    format!("{:02}-{}.txt", day, variant)
+}
+
+/// `$AOC_INPUT_ROOT` if set, `src` of this crate otherwise. Independent of the working directory.
+pub fn input_root() -> PathBuf {
+    match env::var_os(INPUT_ROOT_ENV) {
+        Some(root) => PathBuf::from(root),
+        None => Path::new(env!("CARGO_MANIFEST_DIR")).join("src"),
+    }
+}
+
+/// Converts line endings to `\n` and strips trailing newlines, so every day sees the same shape of input.
+pub fn normalize(contents: &str) -> String {
+    contents
+        .replace("\r\n", "\n")
+        .trim_end_matches('\n')
+        .to_string()
+}
+
+pub(crate) fn read_path(path: &Path) -> Result<String, InputError> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(normalize(&contents)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Err(InputError::NotFound(path.into())),
+        Err(e) => Err(InputError::Io {
+            path: path.into(),
+            source: e,
+        }),
+    }
+}
+
+pub fn file_path(folder: &str, day: u8) -> PathBuf {
+    input_root().join(folder).join(file_name(day))
+}
+
+pub fn read_file(folder: &str, day: u8) -> Result<String, InputError> {
+    read_path(&file_path(folder, day))
+}
+
+/// Reads the example for one part of a day from `examples/<NN>-<part>.txt`,
+/// falling back to the shared `examples/<NN>.txt`.
+pub fn read_example(day: u8, part: u8) -> Result<String, InputError> {
+    let folder = input_root().join("examples");
+
+    match read_path(&folder.join(example_file_name(day, &part.to_string()))) {
+        Err(InputError::NotFound(_)) => read_path(&folder.join(file_name(day))),
+        result => result,
+    }
+}
+
+/// Reads the input of a solution binary as configured by its arguments.
+/// Prints the error and exits if the input cannot be read.
+pub fn read_input(day: u8) -> String {
+    match Source::from_env(day).and_then(|source| source.read()) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("error: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(args: &[&str]) -> Vec<String> {
+        args.iter().map(|a| a.to_string()).collect()
+    }
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("a\r\nb\r\n\r\n"), "a\nb");
+        assert_eq!(normalize("  | \n  +\n"), "  | \n  +");
+    }
+
+    #[test]
+    fn test_from_args() {
+        assert_eq!(
+            Source::from_args(&args(&[]), 3).unwrap(),
+            Source::Day {
+                root: None,
+                folder: "inputs".to_string(),
+                day: 3
+            }
+        );
+        assert_eq!(
+            Source::from_args(&args(&["--input-root", "/tmp/aoc"]), 3).unwrap(),
+            Source::Day {
+                root: Some(PathBuf::from("/tmp/aoc")),
+                folder: "inputs".to_string(),
+                day: 3
+            }
+        );
+        assert_eq!(
+            Source::from_args(&args(&["--input", "day3.txt"]), 3).unwrap(),
+            Source::File(PathBuf::from("day3.txt"))
+        );
+        assert_eq!(
+            Source::from_args(&args(&["--input", "-"]), 3).unwrap(),
+            Source::Stdin
+        );
+        assert!(matches!(
+            Source::from_args(&args(&["--input"]), 3),
+            Err(InputError::Args(_))
+        ));
+
+        // the order of the arguments does not matter, combining both is an error either way.
+        for order in [
+            ["--input", "a.txt", "--input-root", "/tmp"],
+            ["--input-root", "/tmp", "--input", "a.txt"],
+        ] {
+            assert!(matches!(
+                Source::from_args(&args(&order), 3),
+                Err(InputError::Args(_))
+            ));
+        }
+
+        assert_eq!(
+            Source::from_args(&args(&["--render", "disk.ppm", "--input", "-"]), 14).unwrap(),
+            Source::Stdin
+        );
+    }
+
+    #[test]
+    fn test_read_file() {
+        assert!(read_file("examples", 24).unwrap().starts_with("0/2"));
+        assert!(matches!(
+            read_file("examples", 99),
+            Err(InputError::NotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_read_example() {
+        assert!(read_example(18, 2).unwrap().starts_with("snd 1"));
+        assert!(read_example(24, 2).unwrap().starts_with("0/2"));
+    }
+}
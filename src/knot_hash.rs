@@ -1,17 +1,27 @@
-const GRID_SIZE: usize = 256;
+use std::error::Error;
+use std::fmt::{self, Display, Write};
+use std::str::FromStr;
+use std::thread;
 
-pub fn get_int_list() -> [usize; GRID_SIZE] {
-    let mut int_list = [0; GRID_SIZE];
+/// Parameters of the knot hash used by days 10 and 14.
+pub const LIST_SIZE: usize = 256;
+pub const ROUNDS: usize = 64;
+pub const SUFFIX: [usize; 5] = [17, 31, 73, 47, 23];
+pub const BLOCK_SIZE: usize = 16;
 
-    for (i, v) in int_list.iter_mut().enumerate() {
-        *v = i
-    }
-
-    int_list
-}
+/// Number of bytes in a knot hash with the default parameters.
+pub const DIGEST_SIZE: usize = LIST_SIZE / BLOCK_SIZE;
 
-pub fn hash_round(lens: &[usize], list: &mut [usize], pos: &mut usize, skip: &mut usize) {
+/// Panics if a length is larger than the list, the puzzle defines such lengths as invalid.
+pub fn hash_round<T>(lens: &[usize], list: &mut [T], pos: &mut usize, skip: &mut usize) {
     for &len in lens {
+        assert!(
+            len <= list.len(),
+            "length {} is larger than the list of {} elements",
+            len,
+            list.len()
+        );
+
         if len > 1 {
             for i in 0..(len / 2) {
                 list.swap((*pos + i) % list.len(), (*pos + len - i - 1) % list.len());
@@ -23,27 +33,264 @@ pub fn hash_round(lens: &[usize], list: &mut [usize], pos: &mut usize, skip: &mu
     }
 }
 
-pub fn knot_hash(input: &str) -> String {
-    let mut lens: Vec<usize> = input.as_bytes().iter().map(|&c| c as usize).collect();
-    lens.append(&mut vec![17, 31, 73, 47, 23]);
+/// Builds a knot hash with custom parameters, the default is the hash of day 10.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct KnotHasher {
+    size: usize,
+    rounds: usize,
+    suffix: Vec<usize>,
+    block_size: usize,
+}
+
+impl Default for KnotHasher {
+    fn default() -> Self {
+        KnotHasher {
+            size: LIST_SIZE,
+            rounds: ROUNDS,
+            suffix: SUFFIX.to_vec(),
+            block_size: BLOCK_SIZE,
+        }
+    }
+}
+
+impl KnotHasher {
+    pub fn new() -> Self {
+        KnotHasher::default()
+    }
+
+    /// Number of elements in the circular list.
+    pub fn size(mut self, size: usize) -> Self {
+        assert!(size > 0, "the list must not be empty");
+        self.size = size;
+        self
+    }
+
+    pub fn rounds(mut self, rounds: usize) -> Self {
+        self.rounds = rounds;
+        self
+    }
+
+    /// Lengths appended to the input before hashing.
+    pub fn suffix(mut self, suffix: &[usize]) -> Self {
+        self.suffix = suffix.to_vec();
+        self
+    }
+
+    /// Number of elements which are xor-ed into one element of the dense hash.
+    pub fn block_size(mut self, block_size: usize) -> Self {
+        assert!(block_size > 0, "blocks must not be empty");
+        self.block_size = block_size;
+        self
+    }
+
+    /// The list after all rounds, using `lens` followed by the suffix as lengths.
+    /// Panics if a length is larger than the list.
+    pub fn sparse(&self, lens: &[usize]) -> Vec<usize> {
+        let lens: Vec<usize> = lens.iter().chain(self.suffix.iter()).copied().collect();
+        let mut list: Vec<usize> = (0..self.size).collect();
+        let mut pos = 0;
+        let mut skip = 0;
+
+        for _ in 0..self.rounds {
+            hash_round(&lens, &mut list, &mut pos, &mut skip);
+        }
+
+        list
+    }
+
+    /// The sparse hash with each block reduced by xor. A trailing partial block is reduced as well.
+    pub fn dense(&self, lens: &[usize]) -> Vec<usize> {
+        self.sparse(lens)
+            .chunks(self.block_size)
+            .map(|c| c.iter().fold(0, |acc, curr| acc ^ curr))
+            .collect()
+    }
+
+    /// The dense hash of the bytes of `input`, one byte per block.
+    /// Panics if a block does not fit in a byte, which needs a list of more than 256 elements.
+    pub fn digest(&self, input: &[u8]) -> Vec<u8> {
+        let lens: Vec<usize> = input.iter().map(|&b| usize::from(b)).collect();
+
+        self.dense(&lens)
+            .into_iter()
+            .map(|n| u8::try_from(n).expect("blocks of lists larger than 256 do not fit in a byte"))
+            .collect()
+    }
+
+    /// Hashes the bytes of `input` and formats the dense hash as hex.
+    pub fn hash(&self, input: &str) -> String {
+        to_hex(&self.digest(input.as_bytes()))
+    }
+}
+
+/// Hashes input in pieces with the default parameters. Every round runs over all of
+/// the input, so it is buffered until `finalize`.
+#[derive(Clone, Debug, Default)]
+pub struct KnotHash {
+    input: Vec<u8>,
+}
+
+impl KnotHash {
+    pub fn new() -> Self {
+        KnotHash::default()
+    }
+
+    pub fn update(&mut self, data: impl AsRef<[u8]>) {
+        self.input.extend_from_slice(data.as_ref());
+    }
+
+    pub fn finalize(self) -> [u8; DIGEST_SIZE] {
+        knot_hash_bytes(&self.input)
+    }
+}
+
+pub fn knot_hash_bytes(input: &[u8]) -> [u8; DIGEST_SIZE] {
+    KnotHasher::default()
+        .digest(input)
+        .try_into()
+        .expect("the default parameters give a digest of DIGEST_SIZE bytes")
+}
+
+/// Hashes every input like `knot_hash_bytes`, spread across one thread per available core.
+/// The digests are returned in the order of the inputs.
+pub fn knot_hash_batch<T: AsRef<[u8]> + Sync>(inputs: &[T]) -> Vec<[u8; DIGEST_SIZE]> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    knot_hash_batch_with(inputs, threads)
+}
+
+/// Like `knot_hash_batch`, with a fixed number of threads.
+pub fn knot_hash_batch_with<T: AsRef<[u8]> + Sync>(
+    inputs: &[T],
+    threads: usize,
+) -> Vec<[u8; DIGEST_SIZE]> {
+    if threads <= 1 || inputs.len() <= 1 {
+        return inputs.iter().map(|i| knot_hash_bytes(i.as_ref())).collect();
+    }
+
+    let chunk_size = inputs.len().div_ceil(threads);
+
+    thread::scope(|scope| {
+        let handles: Vec<_> = inputs
+            .chunks(chunk_size)
+            .map(|chunk| {
+                scope.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|i| knot_hash_bytes(i.as_ref()))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect();
+
+        handles
+            .into_iter()
+            .flat_map(|handle| handle.join().unwrap())
+            .collect()
+    })
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum DigestError {
+    /// The digest does not have two hex characters per byte.
+    Length(usize),
+    Hex(char),
+}
+
+impl Display for DigestError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DigestError::Length(len) => write!(
+                f,
+                "expected {} hex characters, got {}",
+                DIGEST_SIZE * 2,
+                len
+            ),
+            DigestError::Hex(c) => write!(f, "invalid hex character '{}'", c),
+        }
+    }
+}
+
+impl Error for DigestError {}
+
+/// A knot hash with the default parameters. Displays as lowercase hex.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct KnotDigest(pub [u8; DIGEST_SIZE]);
+
+impl KnotDigest {
+    pub fn of(input: impl AsRef<[u8]>) -> Self {
+        KnotDigest(knot_hash_bytes(input.as_ref()))
+    }
+
+    pub fn as_bytes(&self) -> &[u8; DIGEST_SIZE] {
+        &self.0
+    }
+
+    /// The bit at `index`, counting from the most significant bit of the first byte.
+    pub fn bit(&self, index: usize) -> bool {
+        self.0[index / 8] & (0x80 >> (index % 8)) != 0
+    }
+
+    /// All bits, in the order of `bit`.
+    pub fn bits(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..DIGEST_SIZE * 8).map(move |i| self.bit(i))
+    }
+
+    /// Number of set bits.
+    pub fn count_ones(&self) -> u32 {
+        self.0.iter().map(|b| b.count_ones()).sum()
+    }
+}
+
+impl From<[u8; DIGEST_SIZE]> for KnotDigest {
+    fn from(bytes: [u8; DIGEST_SIZE]) -> Self {
+        KnotDigest(bytes)
+    }
+}
+
+impl Display for KnotDigest {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        for b in self.0 {
+            write!(f, "{:02x}", b)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for KnotDigest {
+    type Err = DigestError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+
+        if let Some(c) = s.chars().find(|c| !c.is_ascii_hexdigit()) {
+            return Err(DigestError::Hex(c));
+        }
+
+        if s.len() != DIGEST_SIZE * 2 {
+            return Err(DigestError::Length(s.len()));
+        }
 
-    let mut list = get_int_list();
-    let mut pos = 0;
-    let mut skip = 0;
+        let mut bytes = [0; DIGEST_SIZE];
+        for (i, b) in bytes.iter_mut().enumerate() {
+            // only ascii hex digits remain, so slicing and parsing cannot fail.
+            *b = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).unwrap();
+        }
 
-    for _ in 0..64 {
-        hash_round(&lens, &mut list, &mut pos, &mut skip);
+        Ok(KnotDigest(bytes))
     }
+}
 
-    list.chunks(16)
-        .map(|c| {
-            let n = c.iter().cloned().reduce(|acc, curr| acc ^ curr).unwrap();
-            format!("{:02x}", n)
-        })
-        .fold(String::new(), |mut acc, curr| {
-            acc.push_str(&curr);
-            acc
-        })
+/// Formats bytes as lowercase hex.
+pub fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().fold(String::new(), |mut hex, b| {
+        write!(hex, "{:02x}", b).unwrap();
+        hex
+    })
+}
+
+pub fn knot_hash(input: &str) -> String {
+    KnotHasher::default().hash(input)
 }
 
 #[cfg(test)]
@@ -57,4 +304,76 @@ mod tests {
         assert_eq!(knot_hash("1,2,3"), "3efbe78a8d82f29979031a4aa0b16a9d");
         assert_eq!(knot_hash("1,2,4"), "63960835bcdc130f0b66d7ff4f6a5a8e");
     }
+
+    #[test]
+    fn test_hasher() {
+        let single_round = KnotHasher::new().size(5).rounds(1).suffix(&[]);
+        assert_eq!(single_round.sparse(&[3, 4, 1, 5]), vec![3, 4, 2, 1, 0]);
+
+        assert_eq!(
+            single_round.clone().block_size(2).dense(&[3, 4, 1, 5]),
+            vec![3 ^ 4, 2 ^ 1, 0]
+        );
+        assert_eq!(
+            single_round.clone().block_size(2).digest(&[3, 4, 1, 5]),
+            vec![3 ^ 4, 2 ^ 1, 0]
+        );
+        assert_eq!(single_round.block_size(1).hash(""), "0001020304");
+    }
+
+    #[test]
+    #[should_panic(expected = "length 17 is larger than the list of 5 elements")]
+    fn test_length_larger_than_list() {
+        KnotHasher::new().size(5).sparse(&[3, 4, 1, 5]);
+    }
+
+    #[test]
+    fn test_hash_bytes() {
+        let digest = knot_hash_bytes(b"1,2,3");
+        assert_eq!(digest[..2], [0x3e, 0xfb]);
+        assert_eq!(to_hex(&digest), KnotHasher::new().hash("1,2,3"));
+
+        let mut hasher = KnotHash::new();
+        hasher.update("1,");
+        hasher.update(b"2,3");
+        assert_eq!(hasher.finalize(), digest);
+    }
+
+    #[test]
+    fn test_batch() {
+        let inputs: Vec<String> = (0..10).map(|i| format!("flqrgnkx-{}", i)).collect();
+        let sequential: Vec<_> = inputs
+            .iter()
+            .map(|i| knot_hash_bytes(i.as_bytes()))
+            .collect();
+
+        for threads in [1, 3, 4, 16] {
+            assert_eq!(knot_hash_batch_with(&inputs, threads), sequential);
+        }
+
+        assert_eq!(knot_hash_batch(&inputs), sequential);
+        assert!(knot_hash_batch::<&str>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_digest() {
+        let digest = KnotDigest::of("AoC 2017");
+
+        assert_eq!(digest.to_string(), knot_hash("AoC 2017"));
+        assert_eq!(digest.to_string().parse(), Ok(digest));
+        assert_eq!("33EFEB34EA91902BB2F59C9920CAA6CD\n".parse(), Ok(digest));
+        assert_eq!("33ef".parse::<KnotDigest>(), Err(DigestError::Length(4)));
+        assert_eq!("3g".parse::<KnotDigest>(), Err(DigestError::Hex('g')));
+
+        // 0x33 = 0b0011_0011
+        assert_eq!(
+            digest.bits().take(8).collect::<Vec<_>>(),
+            vec![false, false, true, true, false, false, true, true]
+        );
+        assert!(digest.bit(127));
+        assert_eq!(
+            digest.count_ones() as usize,
+            digest.bits().filter(|&b| b).count()
+        );
+    }
 }
@@ -1,118 +1,206 @@
-use std::env;
-use std::fs;
-
+use answers::Verdict;
+use std::fmt::{self, Display};
+use std::time::{Duration, Instant};
+
+pub mod answers;
+pub mod bench;
+pub mod disk;
+pub mod download;
+pub mod duet;
 pub mod grid;
+pub mod hex;
+pub mod history;
+pub mod input;
 pub mod knot_hash;
+pub mod manifest;
+pub mod scaffold;
+pub mod turing;
+
+pub use input::{file_path, read_example, read_file, read_input};
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// When this environment variable is set, `solve!` prints one [Record] per part instead of the formatted output.
+pub const RECORDS_ENV: &str = "AOC_RECORDS";
+
+const RECORD_PREFIX: &str = "aoc-record";
+
 #[macro_export]
 macro_rules! solve {
     ($input:expr, $part_one:ident, $part_two:ident) => {{
-        use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+        use aoc::answers::Answers;
+        use aoc::bench;
+        use aoc::input::Source;
+        use aoc::{print_result, Record, ANSI_ITALIC, ANSI_RESET, RECORDS_ENV};
         use std::fmt::Display;
         use std::time::Instant;
 
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> T, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            println!(
-                "{} {}(elapsed: {:.2?}){}",
-                result, ANSI_ITALIC, elapsed, ANSI_RESET
-            );
+        fn run_part<T: Display>(func: impl Fn(&str) -> T, input: &str, part: u8) {
+            let day = env!("CARGO_BIN_NAME").parse().unwrap_or(0);
+
+            let (result, elapsed, stats) = match bench::Config::from_env() {
+                Some(config) => {
+                    let (result, stats) = bench::run(&config, || func(input));
+                    (result, stats.median, Some(stats))
+                }
+                None => {
+                    let timer = Instant::now();
+                    let result = func(input);
+                    (result, timer.elapsed(), None)
+                }
+            };
+
+            if std::env::var_os(RECORDS_ENV).is_some() {
+                let record = Record {
+                    day,
+                    part,
+                    answer: result.to_string(),
+                    nanos: elapsed.as_nanos(),
+                };
+                println!("{}", record);
+            } else {
+                // the arguments were already checked when the input was read.
+                let answers = Source::from_env(day)
+                    .map(|source| Answers::for_source(&source))
+                    .unwrap_or_default();
+                let verdict = answers.verify(part, &result.to_string());
+                print_result(part, &result, elapsed, &verdict);
+
+                if let Some(stats) = stats {
+                    println!("{}{}{}", ANSI_ITALIC, stats, ANSI_RESET);
+                }
+            }
         }
 
-        println!("🎄 {}Part 1{} 🎄", ANSI_BOLD, ANSI_RESET);
-        println!("");
-        print_result($part_one, $input);
-        println!("");
-        println!("🎄 {}Part 2{} 🎄", ANSI_BOLD, ANSI_RESET);
-        println!("");
-        print_result($part_two, $input);
+        let input = $input;
+        run_part($part_one, input, 1);
+        run_part($part_two, input, 2);
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
+/// Emits `test_part_one` and `test_part_two` which check the parts against the example manifest.
+#[macro_export]
+macro_rules! example_tests {
+    ($day:expr, $part_one:ident) => {
+        #[test]
+        fn test_part_one() {
+            aoc::manifest::check($day, 1, $part_one);
+        }
+    };
+    ($day:expr, $part_one:ident, $part_two:ident) => {
+        aoc::example_tests!($day, $part_one);
+
+        #[test]
+        fn test_part_two() {
+            aoc::manifest::check($day, 2, $part_two);
+        }
+    };
+}
 
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
+pub fn print_result(part: u8, result: &dyn Display, elapsed: Duration, verdict: &Verdict) {
+    if part > 1 {
+        println!();
+    }
 
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, part, ANSI_RESET);
+    println!();
+    println!(
+        "{} {}(elapsed: {:.2?}){} {}",
+        result, ANSI_ITALIC, elapsed, ANSI_RESET, verdict
+    );
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// The answer of a part which can fail on malformed input. Prints the answer, or the error in its place.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Fallible<T, E>(pub Result<T, E>);
+
+impl<T, E> From<Result<T, E>> for Fallible<T, E> {
+    fn from(result: Result<T, E>) -> Self {
+        Fallible(result)
+    }
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // uses `.contains` istd. of `ends_with`: string may contain ANSI escape sequences
-            // possible formats: see [rust/library/core/src/time.rs](https://git.io/Jy1rI)
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+impl<T: Display, E: Display> Display for Fallible<T, E> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match &self.0 {
+            Ok(answer) => write!(f, "{}", answer),
+            Err(e) => write!(f, "error: {}", e),
         }
-    })
+    }
+}
+
+/// The result of running one part of a day. Printed as a tab-separated line for scripts when
+/// [RECORDS_ENV] is set. Answers are expected to fit on a single line.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Record {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub nanos: u128,
+}
+
+impl Record {
+    pub fn elapsed(&self) -> Duration {
+        Duration::from_nanos(self.nanos as u64)
+    }
+}
+
+impl Display for Record {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "{}\t{}\t{}\t{}\t{}",
+            RECORD_PREFIX, self.day, self.part, self.nanos, self.answer
+        )
+    }
+}
+
+/// A solution for one part. Answers are boxed so days with different answer types share a registry.
+pub type Solver = fn(&str) -> Box<dyn Display>;
+
+/// The entry points of a day, used to run all days in a single process.
+pub struct Day {
+    pub day: u8,
+    pub part_one: Solver,
+    pub part_two: Solver,
+}
+
+impl Day {
+    /// Runs and times both parts against `input`.
+    pub fn run(&self, input: &str) -> [Record; 2] {
+        let run_part = |part: u8, solver: Solver| {
+            let timer = Instant::now();
+            let result = solver(input);
+            let elapsed = timer.elapsed();
+
+            Record {
+                day: self.day,
+                part,
+                answer: result.to_string(),
+                nanos: elapsed.as_nanos(),
+            }
+        };
+
+        [run_part(1, self.part_one), run_part(2, self.part_two)]
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    /// copied from: [rust/library/std/src/macros.rs](https://git.io/Jy1r7)
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
-    }
-
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
-        );
-
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    fn test_record() {
+        let record = Record {
+            day: 25,
+            part: 2,
+            answer: " 0  1 [0] 1 ".to_string(),
+            nanos: 74,
+        };
+
+        assert_eq!(record.to_string(), "aoc-record\t25\t2\t74\t 0  1 [0] 1 ");
+        assert_eq!(record.elapsed(), Duration::from_nanos(74));
     }
 }
@@ -1,35 +1,377 @@
-use aoc::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use aoc::answers::{Answers, Verdict};
+use aoc::download;
+use aoc::history::{self, Change, Entry, Profile};
+use aoc::input::{InputError, Source};
+use aoc::manifest;
+use aoc::scaffold::{self, Template};
+use aoc::{Day, Record, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use std::env;
+use std::fmt::Display;
+use std::panic;
+use std::path::{Path, PathBuf};
+use std::process;
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
+// every solution binary is compiled into this one as a module, `main` and tests are unused here.
+#[allow(dead_code)]
+#[path = "bin/01.rs"]
+mod day01;
+#[allow(dead_code)]
+#[path = "bin/02.rs"]
+mod day02;
+#[allow(dead_code)]
+#[path = "bin/03.rs"]
+mod day03;
+#[allow(dead_code)]
+#[path = "bin/04.rs"]
+mod day04;
+#[allow(dead_code)]
+#[path = "bin/05.rs"]
+mod day05;
+#[allow(dead_code)]
+#[path = "bin/06.rs"]
+mod day06;
+#[allow(dead_code)]
+#[path = "bin/07.rs"]
+mod day07;
+#[allow(dead_code)]
+#[path = "bin/08.rs"]
+mod day08;
+#[allow(dead_code)]
+#[path = "bin/09.rs"]
+mod day09;
+#[allow(dead_code)]
+#[path = "bin/10.rs"]
+mod day10;
+#[allow(dead_code)]
+#[path = "bin/11.rs"]
+mod day11;
+#[allow(dead_code)]
+#[path = "bin/12.rs"]
+mod day12;
+#[allow(dead_code)]
+#[path = "bin/13.rs"]
+mod day13;
+#[allow(dead_code)]
+#[path = "bin/14.rs"]
+mod day14;
+#[allow(dead_code)]
+#[path = "bin/15.rs"]
+mod day15;
+#[allow(dead_code)]
+#[path = "bin/16.rs"]
+mod day16;
+#[allow(dead_code)]
+#[path = "bin/17.rs"]
+mod day17;
+#[allow(dead_code)]
+#[path = "bin/18.rs"]
+mod day18;
+#[allow(dead_code)]
+#[path = "bin/19.rs"]
+mod day19;
+#[allow(dead_code)]
+#[path = "bin/20.rs"]
+mod day20;
+#[allow(dead_code)]
+#[path = "bin/21.rs"]
+mod day21;
+#[allow(dead_code)]
+#[path = "bin/22.rs"]
+mod day22;
+#[allow(dead_code)]
+#[path = "bin/23.rs"]
+mod day23;
+#[allow(dead_code)]
+#[path = "bin/24.rs"]
+mod day24;
+#[allow(dead_code)]
+#[path = "bin/25.rs"]
+mod day25;
+
+macro_rules! day {
+    ($day:literal, $module:ident) => {
+        Day {
+            day: $day,
+            part_one: |input| Box::new($module::part_one(input)),
+            part_two: |input| Box::new($module::part_two(input)),
+        }
+    };
+}
+
+fn days() -> Vec<Day> {
+    vec![
+        day!(1, day01),
+        day!(2, day02),
+        day!(3, day03),
+        day!(4, day04),
+        day!(5, day05),
+        day!(6, day06),
+        day!(7, day07),
+        day!(8, day08),
+        day!(9, day09),
+        day!(10, day10),
+        day!(11, day11),
+        day!(12, day12),
+        day!(13, day13),
+        day!(14, day14),
+        day!(15, day15),
+        day!(16, day16),
+        day!(17, day17),
+        day!(18, day18),
+        day!(19, day19),
+        day!(20, day20),
+        day!(21, day21),
+        day!(22, day22),
+        day!(23, day23),
+        day!(24, day24),
+        day!(25, day25),
+    ]
+}
+
+/// Kept at the root of this repository, regardless of the working directory.
+const HISTORY_FILE: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/timings.csv");
+const DEFAULT_THRESHOLD: f64 = 10_f64;
+
+/// Value of an option passed as `--name <value>`.
+fn option<'a>(args: &'a [String], name: &str) -> Option<&'a str> {
+    args.iter()
+        .position(|a| a == name)
+        .and_then(|i| args.get(i + 1))
+        .map(|v| v.as_str())
+}
+
+fn exit_with_error(message: impl Display) -> ! {
+    eprintln!("error: {}", message);
+    process::exit(1);
+}
+
+/// Parses the day, which is the first positional argument of a subcommand.
+fn parse_day(args: &[String]) -> u8 {
+    match args.first().map(|d| d.parse()) {
+        Some(Ok(day)) if (1..=25).contains(&day) => day,
+        _ => exit_with_error("a day between 1 and 25 is required"),
+    }
+}
+
+/// `download <day> [--year <year>] [--session-file <path>]`
+fn download(args: &[String]) {
+    let day = parse_day(args);
+
+    let year = match option(args, "--year").map(|y| y.parse()) {
+        Some(Ok(year)) => year,
+        Some(Err(_)) => exit_with_error("year must be a number"),
+        None => download::DEFAULT_YEAR,
+    };
+
+    let session = download::read_session(option(args, "--session-file").map(Path::new))
+        .unwrap_or_else(|e| exit_with_error(e));
+
+    println!("Downloading input for day {}, {}...", day, year);
+
+    let input = download::fetch(&download::base_url(), year, day, &session)
+        .unwrap_or_else(|e| exit_with_error(e));
+
+    let path = aoc::file_path("inputs", day);
+    download::write_atomic(&path, &input).unwrap_or_else(|e| exit_with_error(e));
+
+    println!("---");
+    println!("🎄 Successfully wrote input to \"{}\"!", path.display());
+}
+
+fn print_changes(changes: &[Change], threshold: f64, profile: Profile) {
+    println!();
+    println!(
+        "{}Changes since last {} run{} {}(threshold: {}%){}",
+        ANSI_BOLD, profile, ANSI_RESET, ANSI_ITALIC, threshold, ANSI_RESET
+    );
+
+    if changes.is_empty() {
+        println!("No significant changes.");
+        return;
+    }
+
+    println!("| Day | Part |     Before |      After |           Change |");
+    for change in changes {
+        println!("{}", change);
+    }
+}
 
-            let cmd = Command::new("cargo")
-                .args(&["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
+fn print_verdicts(verdicts: &[Verdict]) {
+    let count = |f: fn(&Verdict) -> bool| verdicts.iter().filter(|v| f(v)).count();
 
+    println!(
+        "{}Answers:{} {} correct, {} wrong, {} unknown",
+        ANSI_BOLD,
+        ANSI_RESET,
+        count(|v| *v == Verdict::Correct),
+        count(|v| matches!(v, Verdict::Wrong { .. })),
+        count(|v| *v == Verdict::Unknown)
+    );
+}
+
+/// `scaffold <day> [--force] [--text] [--grid] [--example-per-part]`
+fn scaffold(args: &[String]) {
+    let day = parse_day(args);
+    let has_flag = |name: &str| args.iter().any(|a| a == name);
+
+    let template = Template {
+        text: has_flag("--text"),
+        grid: has_flag("--grid"),
+        example_per_part: has_flag("--example-per-part"),
+    };
+
+    let paths = scaffold::Paths::from_input_root(day, &template);
+    let created = scaffold::create(
+        &paths,
+        &scaffold::render(day, &template),
+        has_flag("--force"),
+    )
+    .unwrap_or_else(|e| exit_with_error(e));
+
+    for path in created {
+        println!("Created \"{}\"", path.display());
+    }
+
+    let has_cases = manifest::load()
+        .unwrap_or_else(|e| exit_with_error(e))
+        .iter()
+        .any(|case| case.day == day);
+
+    if !has_cases {
+        manifest::append(&scaffold::manifest_cases(day, &template))
+            .unwrap_or_else(|e| exit_with_error(e));
+        println!(
+            "Added example cases to \"{}\"",
+            manifest::manifest_path().display()
+        );
+    }
+
+    println!("---");
+    println!("🎄 Type `cargo run --bin {:02}` to run your solution.", day);
+}
+
+/// Runs all days, `[--threshold <percent>]` sets the change in timings which is reported.
+/// `[--input-root <dir>]` reads inputs and answers from another directory, like it does for a single day.
+fn run_all(args: &[String]) {
+    let threshold = match option(args, "--threshold").map(|t| t.parse()) {
+        Some(Ok(threshold)) => threshold,
+        Some(Err(_)) => exit_with_error("threshold must be a number"),
+        None => DEFAULT_THRESHOLD,
+    };
+    let root = match option(args, "--input-root") {
+        Some(root) => Some(PathBuf::from(root)),
+        None if args.iter().any(|a| a == "--input-root") => {
+            exit_with_error("`--input-root` requires a value")
+        }
+        None => None,
+    };
+    let mut verdicts = Vec::new();
+
+    let records: Vec<Record> = days()
+        .iter()
+        .flat_map(|day| {
             println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
+            println!("{}| Day {:02} |{}", ANSI_BOLD, day.day, ANSI_RESET);
             println!("----------");
 
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
+            let source = Source::Day {
+                root: root.clone(),
+                folder: "inputs".to_string(),
+                day: day.day,
+            };
 
-            println!("{}", if is_empty { "Not solved." } else { &output });
+            let input = match source.read() {
+                Ok(input) => input,
+                Err(InputError::NotFound(_)) => {
+                    println!("No input file.");
+                    return vec![];
+                }
+                Err(e) => {
+                    println!("{}", e);
+                    return vec![];
+                }
+            };
 
-            if is_empty {
-                0_f64
-            } else {
-                aoc::parse_exec_time(&output)
+            // a failing day should not abort the remaining ones.
+            let records = match panic::catch_unwind(|| day.run(&input)) {
+                Ok(records) => records,
+                Err(_) => {
+                    println!("Failed.");
+                    return vec![];
+                }
+            };
+
+            let answers = Answers::for_source(&source);
+
+            for record in records.iter() {
+                let verdict = answers.verify(record.part, &record.answer);
+                aoc::print_result(record.part, &record.answer, record.elapsed(), &verdict);
+                verdicts.push(verdict);
             }
+
+            records.into()
         })
-        .sum();
+        .collect();
+
+    let total: u128 = records.iter().map(|r| r.nanos).sum();
 
     println!(
         "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
+        ANSI_BOLD,
+        ANSI_RESET,
+        ANSI_ITALIC,
+        total as f64 / 1_000_000_f64,
+        ANSI_RESET
     );
+
+    print_verdicts(&verdicts);
+
+    let path = Path::new(HISTORY_FILE);
+    let history = history::load(path).unwrap_or_else(|e| {
+        exit_with_error(format!(
+            "could not read timings from \"{}\": {}",
+            path.display(),
+            e
+        ))
+    });
+    let profile = Profile::current();
+    let previous = history::latest_run(&history, profile);
+
+    let run = history::next_run(&history);
+    let current: Vec<Entry> = records
+        .iter()
+        .map(|r| Entry::from_record(run, profile, r))
+        .collect();
+
+    if !previous.is_empty() {
+        print_changes(
+            &history::compare(&previous, &current, threshold),
+            threshold,
+            profile,
+        );
+    }
+
+    if !current.is_empty() {
+        history::append(path, &current).unwrap_or_else(|e| {
+            exit_with_error(format!(
+                "could not write timings to \"{}\": {}",
+                path.display(),
+                e
+            ))
+        });
+    }
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    match args.first().map(|a| a.as_str()) {
+        Some("download") => download(&args[1..]),
+        Some("scaffold") => scaffold(&args[1..]),
+        Some(arg) if !arg.starts_with("--") => exit_with_error(format!(
+            "unknown command `{}`, expected `download`, `scaffold` or options for running all days",
+            arg
+        )),
+        _ => run_all(&args),
+    }
 }
@@ -0,0 +1,206 @@
+use std::fmt::Display;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+
+use crate::input::{input_root, read_path, InputError};
+
+/// Declares example cases for all days, one per line: `day`, `part`, `expected answer` and `input`, separated by tabs.
+/// Inputs are either inline, with `\n` for line breaks, `file:<name>` for a file in `src/examples`
+/// or `empty:` for an empty input.
+pub const MANIFEST_FILE: &str = "manifest.tsv";
+
+const FILE_PREFIX: &str = "file:";
+// an empty field would rely on a trailing tab, which editors tend to strip.
+const EMPTY_INPUT: &str = "empty:";
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Input {
+    Inline(String),
+    File(String),
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Case {
+    /// Line in the manifest, for error messages.
+    pub line: usize,
+    pub day: u8,
+    pub part: u8,
+    pub expected: String,
+    pub input: Input,
+}
+
+impl Case {
+    pub fn read_input(&self) -> Result<String, InputError> {
+        match &self.input {
+            Input::Inline(input) => Ok(input.clone()),
+            Input::File(name) => read_path(&input_root().join("examples").join(name)),
+        }
+    }
+}
+
+fn unescape(s: &str) -> String {
+    let mut result = String::new();
+    let mut chars = s.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            result.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('n') => result.push('\n'),
+            Some('t') => result.push('\t'),
+            Some(c) => result.push(c),
+            None => result.push('\\'),
+        }
+    }
+
+    result
+}
+
+fn parse_line(line: &str) -> Option<(u8, u8, String, Input)> {
+    let mut fields = line.splitn(4, '\t');
+
+    let day = fields.next()?.trim().parse().ok()?;
+    let part = fields
+        .next()?
+        .trim()
+        .parse()
+        .ok()
+        .filter(|p| *p == 1 || *p == 2)?;
+    let expected = unescape(fields.next()?);
+    let input = fields.next()?;
+
+    let input = match input.strip_prefix(FILE_PREFIX) {
+        Some(name) => Input::File(name.to_string()),
+        None if input == EMPTY_INPUT => Input::Inline(String::new()),
+        None => Input::Inline(unescape(input)),
+    };
+
+    Some((day, part, expected, input))
+}
+
+/// Parses a manifest, skipping empty lines and comments starting with `#`.
+pub fn parse(contents: &str) -> Result<Vec<Case>, String> {
+    contents
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.trim_end_matches('\r')))
+        .filter(|(_, line)| !line.trim().is_empty() && !line.starts_with('#'))
+        .map(|(line, contents)| {
+            let (day, part, expected, input) = parse_line(contents)
+                .ok_or_else(|| format!("malformed manifest entry on line {}", line))?;
+
+            Ok(Case {
+                line,
+                day,
+                part,
+                expected,
+                input,
+            })
+        })
+        .collect()
+}
+
+pub fn manifest_path() -> PathBuf {
+    input_root().join("examples").join(MANIFEST_FILE)
+}
+
+pub fn load() -> Result<Vec<Case>, String> {
+    let path = manifest_path();
+    let contents = fs::read_to_string(&path)
+        .map_err(|e| format!("could not read \"{}\": {}", path.display(), e))?;
+    parse(&contents)
+}
+
+/// Appends cases to the manifest, e.g. placeholders for a new day.
+pub fn append(lines: &[String]) -> io::Result<()> {
+    let mut file = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(manifest_path())?;
+
+    for line in lines {
+        writeln!(file, "{}", line)?;
+    }
+
+    Ok(())
+}
+
+/// Runs `solver` against all manifest cases of a day and part.
+/// Panics listing every failing case, or if no case is declared at all.
+pub fn check<T: Display>(day: u8, part: u8, solver: impl Fn(&str) -> T) {
+    let cases = load().unwrap();
+    let cases: Vec<&Case> = cases
+        .iter()
+        .filter(|c| c.day == day && c.part == part)
+        .collect();
+
+    let failures: Vec<String> = cases
+        .iter()
+        .filter_map(|case| {
+            let input = case.read_input().unwrap();
+            let answer = solver(&input).to_string();
+
+            if answer == case.expected {
+                None
+            } else {
+                Some(format!(
+                    "line {}: expected {:?}, got {:?}",
+                    case.line, case.expected, answer
+                ))
+            }
+        })
+        .collect();
+
+    assert!(
+        failures.is_empty(),
+        "day {} part {} failed for {} of {} cases:\n{}",
+        day,
+        part,
+        failures.len(),
+        cases.len(),
+        failures.join("\n")
+    );
+
+    assert!(
+        !cases.is_empty(),
+        "no examples declared for day {} part {}",
+        day,
+        part
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        let cases = parse(
+            "# day\tpart\texpected\tinput\n\n9\t2\t10\t<{o\"i!a,<{i<a>\n4\t1\t2\tfile:04-1.txt\n1\t1\t3\t11\\n22\n10\t2\ta258\tempty:",
+        )
+        .unwrap();
+
+        assert_eq!(cases.len(), 4);
+        assert_eq!(cases[0].line, 3);
+        assert_eq!(cases[0].input, Input::Inline("<{o\"i!a,<{i<a>".to_string()));
+        assert_eq!(cases[1].input, Input::File("04-1.txt".to_string()));
+        assert_eq!(cases[1].expected, "2");
+        assert_eq!(cases[2].input, Input::Inline("11\n22".to_string()));
+        assert_eq!(cases[3].input, Input::Inline(String::new()));
+
+        assert_eq!(
+            parse("9\t3\t1\t{}"),
+            Err("malformed manifest entry on line 1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_manifest() {
+        let cases = load().unwrap();
+        assert!(cases.iter().all(|case| case.read_input().is_ok()));
+    }
+}
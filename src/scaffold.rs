@@ -0,0 +1,252 @@
+use std::error::Error;
+use std::fmt::{self, Display};
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use crate::input::{example_file_name, file_name, input_root};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Template {
+    /// Answers are `String` instead of `u32`.
+    pub text: bool,
+    /// Parses the input into rows of characters.
+    pub grid: bool,
+    /// Checks each part against its own example file.
+    pub example_per_part: bool,
+}
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    Exists(PathBuf),
+    Io { path: PathBuf, source: io::Error },
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(
+                f,
+                "module \"{}\" already exists, pass `--force` to overwrite it",
+                path.display()
+            ),
+            ScaffoldError::Io { path, source } => {
+                write!(f, "could not write \"{}\": {}", path.display(), source)
+            }
+        }
+    }
+}
+
+impl Error for ScaffoldError {}
+
+/// Files created for a day.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Paths {
+    pub module: PathBuf,
+    pub input: PathBuf,
+    pub examples: Vec<PathBuf>,
+}
+
+impl Paths {
+    /// Module in `src/bin` of this crate, input and examples below `root`.
+    pub fn new(day: u8, template: &Template, root: &Path) -> Paths {
+        let examples = if template.example_per_part {
+            vec![
+                root.join("examples").join(example_file_name(day, "1")),
+                root.join("examples").join(example_file_name(day, "2")),
+            ]
+        } else {
+            vec![root.join("examples").join(file_name(day))]
+        };
+
+        Paths {
+            module: Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("src")
+                .join("bin")
+                .join(format!("{:02}.rs", day)),
+            input: root.join("inputs").join(file_name(day)),
+            examples,
+        }
+    }
+
+    pub fn from_input_root(day: u8, template: &Template) -> Paths {
+        Paths::new(day, template, &input_root())
+    }
+}
+
+fn render_part(part: &str, template: &Template) -> String {
+    let answer_type = if template.text { "String" } else { "u32" };
+    let answer = if template.text { "String::new()" } else { "0" };
+
+    if template.grid {
+        format!(
+            "pub fn part_{}(input: &str) -> {} {{\n    let grid = parse(input);\n    {}\n}}\n",
+            part, answer_type, answer
+        )
+    } else {
+        format!(
+            "pub fn part_{}(input: &str) -> {} {{\n    {}\n}}\n",
+            part, answer_type, answer
+        )
+    }
+}
+
+/// Expected answer of scaffolded cases. No stub returns it, so the tests of a new day fail until it is replaced.
+pub const PLACEHOLDER_ANSWER: &str = "TODO";
+
+/// Placeholder manifest cases which check both parts against the new example files.
+pub fn manifest_cases(day: u8, template: &Template) -> Vec<String> {
+    (1..=2)
+        .map(|part| {
+            let name = if template.example_per_part {
+                example_file_name(day, &part.to_string())
+            } else {
+                file_name(day)
+            };
+
+            format!("{}\t{}\t{}\tfile:{}", day, part, PLACEHOLDER_ANSWER, name)
+        })
+        .collect()
+}
+
+/// Renders the source of a solution module.
+pub fn render(day: u8, template: &Template) -> String {
+    let mut source = String::new();
+
+    if template.grid {
+        source.push_str(
+            "use aoc::grid::Grid;\n\nfn parse(input: &str) -> Grid<char> {\n    input.parse().unwrap()\n}\n\n",
+        );
+    }
+
+    source.push_str(&render_part("one", template));
+    source.push('\n');
+    source.push_str(&render_part("two", template));
+    source.push_str(&format!(
+        "\nfn main() {{\n    aoc::solve!(&aoc::read_input({}), part_one, part_two)\n}}\n",
+        day
+    ));
+    source.push_str(&format!(
+        "\n#[cfg(test)]\nmod tests {{\n    use super::*;\n\n    aoc::example_tests!({}, part_one, part_two);\n}}\n",
+        day
+    ));
+
+    source
+}
+
+fn create_empty(path: &Path) -> Result<bool, ScaffoldError> {
+    let io_error = |source| ScaffoldError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+
+    match OpenOptions::new().write(true).create_new(true).open(path) {
+        Ok(_) => Ok(true),
+        Err(e) if e.kind() == io::ErrorKind::AlreadyExists => Ok(false),
+        Err(e) => Err(io_error(e)),
+    }
+}
+
+/// Writes the module and creates empty input and example files.
+/// Existing inputs and examples are kept, an existing module is only replaced with `force`.
+/// Returns the files which were created.
+pub fn create(paths: &Paths, source: &str, force: bool) -> Result<Vec<PathBuf>, ScaffoldError> {
+    if paths.module.exists() && !force {
+        return Err(ScaffoldError::Exists(paths.module.clone()));
+    }
+
+    let mut created = Vec::new();
+
+    fs::File::create(&paths.module)
+        .and_then(|mut file| file.write_all(source.as_bytes()))
+        .map_err(|source| ScaffoldError::Io {
+            path: paths.module.clone(),
+            source,
+        })?;
+    created.push(paths.module.clone());
+
+    for path in std::iter::once(&paths.input).chain(paths.examples.iter()) {
+        if create_empty(path)? {
+            created.push(path.clone());
+        }
+    }
+
+    Ok(created)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+
+    #[test]
+    fn test_render() {
+        let source = render(3, &Template::default());
+        assert!(source.contains("pub fn part_one(input: &str) -> u32 {\n    0\n}"));
+        assert!(source.contains("aoc::solve!(&aoc::read_input(3), part_one, part_two)"));
+        assert!(source.contains("aoc::example_tests!(3, part_one, part_two);"));
+
+        let source = render(
+            19,
+            &Template {
+                text: true,
+                grid: true,
+                example_per_part: true,
+            },
+        );
+        assert!(source.starts_with("use aoc::grid::Grid;\n\nfn parse(input: &str) -> Grid<char> {"));
+        assert!(source
+            .contains("pub fn part_two(input: &str) -> String {\n    let grid = parse(input);"));
+    }
+
+    #[test]
+    fn test_manifest_cases() {
+        assert_eq!(
+            manifest_cases(3, &Template::default()),
+            vec!["3\t1\tTODO\tfile:03.txt", "3\t2\tTODO\tfile:03.txt"]
+        );
+
+        let template = Template {
+            text: true,
+            example_per_part: true,
+            ..Template::default()
+        };
+        let cases = manifest_cases(19, &template);
+        assert_eq!(cases[1], "19\t2\tTODO\tfile:19-2.txt");
+        assert!(crate::manifest::parse(&cases.join("\n")).is_ok());
+    }
+
+    #[test]
+    fn test_create() {
+        let root = env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let template = Template {
+            example_per_part: true,
+            ..Template::default()
+        };
+        let mut paths = Paths::new(7, &template, &root);
+        paths.module = root.join("07.rs");
+
+        fs::create_dir_all(root.join("inputs")).unwrap();
+        fs::write(&paths.input, "pbga (66)").unwrap();
+
+        let created = create(&paths, "fn main() {}", false).unwrap();
+        assert_eq!(created.len(), 3);
+        assert_eq!(fs::read_to_string(&paths.input).unwrap(), "pbga (66)");
+        assert!(root.join("examples").join("07-2.txt").exists());
+
+        assert!(matches!(
+            create(&paths, "", false),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert_eq!(
+            create(&paths, "", true).unwrap(),
+            vec![paths.module.clone()]
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
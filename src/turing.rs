@@ -0,0 +1,321 @@
+use std::collections::{HashMap, VecDeque};
+
+/// A value that can be written to a tape cell. Blank cells hold `0`.
+pub type Symbol = usize;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Move {
+    Left,
+    Right,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Rule {
+    pub write: Symbol,
+    pub direction: Move,
+    pub next: usize,
+}
+
+/// A state holds one rule per symbol that can be read, indexed by that symbol.
+/// Symbols the state has no rule for are `None`, e.g. gaps in the numbering of symbols.
+#[derive(Debug, PartialEq, Eq)]
+pub struct State {
+    pub name: char,
+    pub rules: Vec<Option<Rule>>,
+}
+
+/// The description of a machine: its states, the initial state and the step count for the checksum.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Blueprint {
+    pub states: Vec<State>,
+    pub start: usize,
+    pub checksum_at: usize,
+}
+
+fn last_word(line: &str) -> Option<&str> {
+    line.split(' ')
+        .next_back()
+        .map(|w| w.trim_end_matches(['.', ':']))
+}
+
+fn parse_name(line: &str) -> Option<char> {
+    last_word(line)?.chars().next()
+}
+
+// a rule which references its target state by name.
+type NamedRule = (Symbol, Move, char);
+
+pub fn parse(input: &str) -> Option<Blueprint> {
+    let mut start = None;
+    let mut checksum_at = None;
+
+    // target states are resolved to indices once all states are known.
+    let mut named_states: Vec<(char, Vec<Option<NamedRule>>)> = Vec::new();
+    let mut current: Option<Symbol> = None;
+    let mut write = None;
+    let mut direction = None;
+
+    for line in input.lines().map(|l| l.trim()).filter(|l| !l.is_empty()) {
+        if line.starts_with("Begin in state") {
+            start = parse_name(line);
+        } else if line.starts_with("Perform a diagnostic checksum") {
+            checksum_at = line.split(' ').nth_back(1)?.parse().ok();
+        } else if line.starts_with("In state") {
+            named_states.push((parse_name(line)?, Vec::new()));
+        } else if line.starts_with("If the current value is") {
+            current = Some(last_word(line)?.parse().ok()?);
+        } else if line.starts_with("- Write the value") {
+            write = Some(last_word(line)?.parse().ok()?);
+        } else if line.starts_with("- Move one slot to the") {
+            direction = match last_word(line)? {
+                "left" => Some(Move::Left),
+                "right" => Some(Move::Right),
+                _ => None,
+            };
+        } else if line.starts_with("- Continue with state") {
+            let (_, rules) = named_states.last_mut()?;
+            let symbol = current.take()?;
+
+            if rules.len() <= symbol {
+                rules.resize(symbol + 1, None);
+            }
+
+            rules[symbol] = Some((write.take()?, direction.take()?, parse_name(line)?));
+        }
+    }
+
+    let indices: HashMap<char, usize> = named_states
+        .iter()
+        .enumerate()
+        .map(|(i, (name, _))| (*name, i))
+        .collect();
+
+    let states = named_states
+        .iter()
+        .map(|(name, rules)| {
+            let rules = rules
+                .iter()
+                .map(|rule| match *rule {
+                    Some((write, direction, next)) => Some(Some(Rule {
+                        write,
+                        direction,
+                        next: *indices.get(&next)?,
+                    })),
+                    None => Some(None),
+                })
+                .collect::<Option<Vec<Option<Rule>>>>()?;
+
+            Some(State { name: *name, rules })
+        })
+        .collect::<Option<Vec<State>>>()?;
+
+    Some(Blueprint {
+        start: *indices.get(&start?)?,
+        checksum_at: checksum_at?,
+        states,
+    })
+}
+
+/// An infinite tape, stored densely and grown on demand at either end.
+pub struct Tape {
+    cells: VecDeque<Symbol>,
+    cursor: usize,
+}
+
+impl Default for Tape {
+    fn default() -> Self {
+        let mut cells = VecDeque::new();
+        cells.push_back(0);
+        Tape { cells, cursor: 0 }
+    }
+}
+
+impl Tape {
+    pub fn read(&self) -> Symbol {
+        self.cells[self.cursor]
+    }
+
+    pub fn write(&mut self, symbol: Symbol) {
+        self.cells[self.cursor] = symbol;
+    }
+
+    pub fn shift(&mut self, direction: Move) {
+        match direction {
+            Move::Left => {
+                if self.cursor == 0 {
+                    self.cells.push_front(0);
+                } else {
+                    self.cursor -= 1;
+                }
+            }
+            Move::Right => {
+                self.cursor += 1;
+                if self.cursor == self.cells.len() {
+                    self.cells.push_back(0);
+                }
+            }
+        }
+    }
+
+    /// Number of cells holding `symbol`. Only counts cells that were visited.
+    pub fn count(&self, symbol: Symbol) -> usize {
+        self.cells.iter().filter(|&&s| s == symbol).count()
+    }
+
+    /// Renders the cells within `radius` of the cursor, marking the cursor with brackets.
+    pub fn window(&self, radius: usize) -> String {
+        (-(radius as isize)..=radius as isize)
+            .map(|offset| {
+                let symbol = (self.cursor as isize + offset)
+                    .try_into()
+                    .ok()
+                    .and_then(|i: usize| self.cells.get(i))
+                    .unwrap_or(&0);
+
+                if offset == 0 {
+                    format!("[{}]", symbol)
+                } else {
+                    format!(" {} ", symbol)
+                }
+            })
+            .collect()
+    }
+}
+
+pub struct Machine<'a> {
+    blueprint: &'a Blueprint,
+    pub tape: Tape,
+    pub state: usize,
+    pub steps: usize,
+}
+
+impl<'a> Machine<'a> {
+    pub fn new(blueprint: &'a Blueprint) -> Self {
+        Machine {
+            blueprint,
+            tape: Tape::default(),
+            state: blueprint.start,
+            steps: 0,
+        }
+    }
+
+    pub fn step(&mut self) {
+        let state = &self.blueprint.states[self.state];
+        let symbol = self.tape.read();
+        let rule = state
+            .rules
+            .get(symbol)
+            .copied()
+            .flatten()
+            .unwrap_or_else(|| panic!("state {} has no rule for {}", state.name, symbol));
+
+        self.tape.write(rule.write);
+        self.tape.shift(rule.direction);
+        self.state = rule.next;
+        self.steps += 1;
+    }
+
+    /// Runs at most `steps` steps.
+    pub fn run(&mut self, steps: usize) {
+        for _ in 0..steps {
+            self.step();
+        }
+    }
+
+    /// Runs until the blueprint's checksum step and returns the number of `1` symbols on the tape.
+    pub fn checksum(&mut self) -> usize {
+        self.run(self.blueprint.checksum_at.saturating_sub(self.steps));
+        self.tape.count(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BLUEPRINT: &str = "Begin in state A.
+Perform a diagnostic checksum after 4 steps.
+
+In state A:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the right.
+    - Continue with state B.
+  If the current value is 1:
+    - Write the value 2.
+    - Move one slot to the left.
+    - Continue with state B.
+  If the current value is 2:
+    - Write the value 0.
+    - Move one slot to the right.
+    - Continue with state A.
+
+In state B:
+  If the current value is 0:
+    - Write the value 1.
+    - Move one slot to the left.
+    - Continue with state A.
+  If the current value is 1:
+    - Write the value 2.
+    - Move one slot to the right.
+    - Continue with state A.
+  If the current value is 2:
+    - Write the value 0.
+    - Move one slot to the right.
+    - Continue with state A.";
+
+    #[test]
+    fn test_parse() {
+        let blueprint = parse(BLUEPRINT).unwrap();
+        assert_eq!(blueprint.start, 0);
+        assert_eq!(blueprint.checksum_at, 4);
+        assert_eq!(blueprint.states.len(), 2);
+        assert_eq!(
+            blueprint.states[0].rules[1],
+            Some(Rule {
+                write: 2,
+                direction: Move::Left,
+                next: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_machine() {
+        let blueprint = parse(BLUEPRINT).unwrap();
+        let mut machine = Machine::new(&blueprint);
+
+        machine.run(2);
+        assert_eq!(machine.tape.window(1), " 0 [1] 1 ");
+
+        assert_eq!(machine.checksum(), 2);
+        assert_eq!(machine.steps, 4);
+        assert_eq!(machine.tape.count(2), 1);
+        assert_eq!(machine.tape.window(1), " 0 [0] 1 ");
+    }
+
+    #[test]
+    fn test_sparse_rules() {
+        let blueprint = parse(
+            "Begin in state A.
+Perform a diagnostic checksum after 1 steps.
+In state A:
+  If the current value is 0:
+    - Write the value 2.
+    - Move one slot to the right.
+    - Continue with state A.
+  If the current value is 2:
+    - Write the value 0.
+    - Move one slot to the left.
+    - Continue with state A.",
+        )
+        .unwrap();
+
+        assert_eq!(blueprint.states[0].rules.len(), 3);
+        assert_eq!(blueprint.states[0].rules[1], None);
+
+        let mut machine = Machine::new(&blueprint);
+        assert_eq!(machine.checksum(), 0);
+        assert_eq!(machine.tape.count(2), 1);
+    }
+}